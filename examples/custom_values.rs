@@ -15,12 +15,12 @@ fn main() -> Result<(), das_grid::GridErr> {
     let mut g = das_grid::Grid::new((2, 2), (32., 32.), Pawn::None);
 
     // Set the Player on position 5,5
-    g.set((0, 0), &Pawn::Player)?;
+    g.set((0, 0), Pawn::Player)?;
 
     println!("Initial state {:?}\n", g);
 
     // Move the player to right
-    if let Ok(()) = g.mov_to((0, 0), das_grid::MoveDirection::Right) {
+    if let Ok(Pawn::None) = g.mov_to((0, 0), das_grid::MoveDirection::Right) {
         // "The pawn on 5,6 is Player"
         println!("The pawn on 0, 1 is {}\n", g.get((0, 1)).unwrap());
     }
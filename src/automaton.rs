@@ -0,0 +1,197 @@
+use crate::neighbors::Stencil;
+use crate::{Grid, MoveDirection, Offset, Pos, Topology};
+
+/// Represents how the cells on the edges of the grid see their neighbors
+///
+/// Edges::Clamp stops on the edges, the neighbors out of the grid are skipped,
+/// unless the topology of the grid wraps them
+///
+/// Edges::Wrap wraps around, the neighbors out of one side are taken from the other side,
+/// like a grid with Topology::Torus
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Edges {
+    #[default]
+    Clamp,
+    Wrap,
+}
+
+/// Represents the neighbors of a cell given to the rule of an automaton,
+/// the values are read from the current generation
+pub struct Neighbors<'a, T> {
+    cells: Stencil<'a, Grid<T>>,
+}
+
+impl<'a, T> Neighbors<'a, T> {
+    /// The position of the cell
+    pub fn pos(&self) -> Pos {
+        self.cells.pos()
+    }
+}
+
+impl<'a, T> Iterator for Neighbors<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cells.next().map(|(_, value)| value)
+    }
+}
+
+/// Represents a cellular automaton, which computes each generation of a grid
+/// from the rule `next = rule(cell, neighbors)`
+///
+/// The next generation is written on a back buffer which is swapped with the grid cells,
+/// the buffer is allocated on the first step and reused by the next ones
+///
+/// ```.rust
+/// use das_grid::{Automaton, Edges, Grid};
+///
+/// // The blinker of the Game of Life
+/// let mut grid = Grid::new((5, 5), (1., 1.), false);
+/// for col in 1..4 {
+///     grid.set((2, col), true);
+/// }
+///
+/// let life = |alive: &bool, neighbors: das_grid::Neighbors<bool>| {
+///     let count = neighbors.filter(|n| **n).count();
+///     matches!((alive, count), (true, 2) | (_, 3))
+/// };
+///
+/// let mut automaton = Automaton::new(Edges::Clamp);
+/// assert!(automaton.step(&mut grid, life));
+/// assert_eq!(grid.get_col(2).unwrap(), vec![false, true, true, true, false]);
+/// assert_eq!(automaton.generation(), 1);
+/// ```
+pub struct Automaton<T> {
+    offsets: Vec<Offset>,
+    edges: Edges,
+    back: Vec<T>,
+    generation: usize,
+}
+
+impl<T> Automaton<T> {
+    /// Creates an automaton where the neighbors are the 8 surrounding cells (Moore neighborhood)
+    pub fn new(edges: Edges) -> Self {
+        let offsets = MoveDirection::ALL.iter().map(|d| d.offset()).collect();
+        Self::with_neighborhood(edges, offsets)
+    }
+
+    /// Creates an automaton where the neighbors are the cells at the given offsets,
+    /// like the orthogonal directions for the von Neumann neighborhood
+    pub fn with_neighborhood(edges: Edges, offsets: Vec<Offset>) -> Self {
+        Self {
+            offsets,
+            edges,
+            back: vec![],
+            generation: 0,
+        }
+    }
+
+    /// The amount of generations computed by the automaton that changed the grid,
+    /// the steps on a fixed point aren't counted
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Computes the next generation of the grid
+    ///
+    /// Returns false if no cell changed, the grid has reached a fixed point
+    pub fn step<F>(&mut self, grid: &mut Grid<T>, rule: F) -> bool
+    where
+        T: Clone + PartialEq,
+        F: Fn(&T, Neighbors<T>) -> T,
+    {
+        self.next_generation(grid, &rule)
+    }
+
+    /// Computes up to n generations of the grid, stopping early on a fixed point
+    ///
+    /// Returns the amount of generations that changed the grid
+    ///
+    /// ```.rust
+    /// use das_grid::{Automaton, Edges, Grid};
+    ///
+    /// // A fire spreading over a line of trees until everything is burned
+    /// let mut grid = Grid::new_from_vector((1, 5), (1., 1.), vec!['*', 'T', 'T', '.', 'T']);
+    /// let fire = |cell: &char, mut neighbors: das_grid::Neighbors<char>| match cell {
+    ///     'T' if neighbors.any(|n| *n == '*') => '*',
+    ///     '*' => '.',
+    ///     other => *other,
+    /// };
+    ///
+    /// let mut automaton = Automaton::new(Edges::Clamp);
+    /// assert_eq!(automaton.run(&mut grid, 100, fire), 3);
+    /// assert_eq!(grid.get_flatten_grid(), vec!['.', '.', '.', '.', 'T']);
+    /// ```
+    pub fn run<F>(&mut self, grid: &mut Grid<T>, n: usize, rule: F) -> usize
+    where
+        T: Clone + PartialEq,
+        F: Fn(&T, Neighbors<T>) -> T,
+    {
+        for changed in 0..n {
+            if !self.next_generation(grid, &rule) {
+                return changed;
+            }
+        }
+        n
+    }
+
+    // Writes the next generation on the back buffer and swaps it with the grid cells
+    fn next_generation<F>(&mut self, grid: &mut Grid<T>, rule: &F) -> bool
+    where
+        T: Clone + PartialEq,
+        F: Fn(&T, Neighbors<T>) -> T,
+    {
+        if self.back.len() != grid.cells.len() {
+            self.back = grid.cells.clone();
+        }
+
+        // The neighbors wrap with the topology of the grid, and on every axis while wrapping
+        let topology = match self.edges {
+            Edges::Clamp => Topology::Bounded,
+            Edges::Wrap => Topology::Torus,
+        };
+
+        let mut changed = false;
+        for ((pos, cell), next) in grid.positions().zip(&grid.cells).zip(self.back.iter_mut()) {
+            let neighbors = Neighbors {
+                cells: Stencil::new(&*grid, pos, &self.offsets).wrapping(topology),
+            };
+            *next = rule(cell, neighbors);
+            changed |= next != cell;
+        }
+
+        std::mem::swap(&mut grid.cells, &mut self.back);
+        if changed {
+            self.generation += 1;
+        }
+        changed
+    }
+}
+
+impl<T> Grid<T> {
+    /// Computes the next generation of the grid with the rule of a cellular automaton,
+    /// the neighbors are the 8 surrounding cells
+    ///
+    /// Returns false if no cell changed, the grid has reached a fixed point
+    ///
+    /// Each call allocates the next generation, use an `Automaton` to run many generations
+    ///
+    /// ```.rust
+    /// use das_grid::Edges;
+    ///
+    /// // Cave generation, a wall stays with 4 walls around and a floor becomes wall with 5
+    /// let mut grid = das_grid::Grid::new_from_vector((3, 3), (1., 1.), "##.#..##.".chars().collect());
+    /// grid.step(Edges::Clamp, |cell, neighbors| {
+    ///     let walls = neighbors.filter(|n| **n == '#').count();
+    ///     if walls >= 5 || (*cell == '#' && walls >= 4) { '#' } else { '.' }
+    /// });
+    /// assert_eq!(grid.get((1, 1)), Ok(&'#'));
+    /// ```
+    pub fn step<F>(&mut self, edges: Edges, rule: F) -> bool
+    where
+        T: Clone + PartialEq,
+        F: Fn(&T, Neighbors<T>) -> T,
+    {
+        Automaton::new(edges).step(self, rule)
+    }
+}
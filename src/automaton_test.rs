@@ -0,0 +1,135 @@
+#![allow(warnings, unused)]
+#[macro_use]
+use pretty_assertions::{assert_eq, assert_ne};
+
+use crate::{Automaton, Edges, Grid, MoveDirection, Neighbors, Topology};
+
+fn life(alive: &bool, neighbors: Neighbors<bool>) -> bool {
+    let count = neighbors.filter(|n| **n).count();
+    matches!((alive, count), (true, 2) | (_, 3))
+}
+
+#[test]
+fn test_still_life_fixed_point() {
+    let block = "\
+        ....\
+        .##.\
+        .##.\
+        ....\
+        ";
+    let mut g = Grid::new_from_vector((4, 4), (1., 1.), block.chars().map(|c| c == '#').collect());
+    let mut automaton = Automaton::new(Edges::Clamp);
+    assert!(!automaton.step(&mut g, life));
+    assert_eq!(automaton.run(&mut g, 10, life), 0);
+    assert_eq!(automaton.generation(), 0);
+    assert_eq!(
+        g.get_flatten_grid(),
+        block.chars().map(|c| c == '#').collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_oscillator_never_settles() {
+    let mut g = Grid::new_from_vector(
+        (5, 5),
+        (1., 1.),
+        ".......#....#....#......."
+            .chars()
+            .map(|c| c == '#')
+            .collect(),
+    );
+    let mut automaton = Automaton::new(Edges::Clamp);
+    assert_eq!(automaton.run(&mut g, 7, life), 7);
+    assert_eq!(g.get_row(2).unwrap(), vec![false, true, true, true, false]);
+    assert_eq!(automaton.run(&mut g, 1, life), 1);
+    assert_eq!(g.get_col(2).unwrap(), vec![false, true, true, true, false]);
+    assert_eq!(automaton.generation(), 8);
+}
+
+#[test]
+fn test_glider_wraps_around() {
+    let glider = "\
+        .#...\
+        ..#..\
+        ###..\
+        .....\
+        .....\
+        ";
+    // A glider moves one cell diagonally every 4 generations
+    let mut g = Grid::new_from_vector((5, 5), (1., 1.), glider.chars().map(|c| c == '#').collect());
+    let mut automaton = Automaton::new(Edges::Wrap);
+    assert_eq!(automaton.run(&mut g, 20, life), 20);
+    assert_eq!(
+        g.get_flatten_grid(),
+        glider.chars().map(|c| c == '#').collect::<Vec<_>>()
+    );
+
+    // Without wrapping it turns into a block on the corner
+    let mut g = Grid::new_from_vector((5, 5), (1., 1.), glider.chars().map(|c| c == '#').collect());
+    let mut automaton = Automaton::new(Edges::Clamp);
+    let changed = automaton.run(&mut g, 100, life);
+    assert!(changed < 100);
+    assert_eq!(automaton.generation(), changed);
+    assert_eq!(
+        g.get_flatten_grid(),
+        "\
+        .....\
+        .....\
+        .....\
+        ...##\
+        ...##\
+        "
+        .chars()
+        .map(|c| c == '#')
+        .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_custom_neighborhood() {
+    let orthogonal = MoveDirection::ORTHOGONAL
+        .iter()
+        .map(|d| d.offset())
+        .collect();
+    let mut automaton = Automaton::with_neighborhood(Edges::Wrap, orthogonal);
+
+    // Every cell counts its neighbors, on a wrapped grid every cell has 4
+    let mut g = Grid::new((3, 3), (1., 1.), 1);
+    assert!(automaton.step(&mut g, |_, n| n.count() as i32));
+    assert_eq!(g.get_flatten_grid(), vec![4; 9]);
+
+    let mut g = Grid::new((3, 3), (1., 1.), 1);
+    assert!(g.step(Edges::Clamp, |_, n| n.count() as i32));
+    assert_eq!(g.get_flatten_grid(), vec![3, 5, 3, 5, 8, 5, 3, 5, 3]);
+
+    // The neighbors know the position of the cell
+    let mut g = Grid::new((2, 2), (1., 1.), (0, 0));
+    g.step(Edges::Clamp, |_, n| (n.pos().row, n.pos().col));
+    assert_eq!(g.get_flatten_grid(), vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+}
+
+#[test]
+fn test_neighbors_follow_topology() {
+    // The clamped edges still wrap the axes of the topology of the grid
+    let mut g = Grid::new((3, 3), (1., 1.), 1);
+    g.set_topology(Topology::WrapX);
+    assert!(g.step(Edges::Clamp, |_, n| n.count() as i32));
+    assert_eq!(g.get_flatten_grid(), vec![5, 5, 5, 8, 8, 8, 5, 5, 5]);
+
+    // Wrapping doesn't change the topology of the grid
+    let mut g = Grid::new((3, 3), (1., 1.), 1);
+    assert!(g.step(Edges::Wrap, |_, n| n.count() as i32));
+    assert_eq!(g.get_flatten_grid(), vec![8; 9]);
+    assert_eq!(g.topology(), Topology::Bounded);
+}
+
+#[test]
+fn test_wrap_keeps_topology_on_panic() {
+    let mut g = Grid::new((2, 2), (1., 1.), 0);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        g.step(Edges::Wrap, |_, _| panic!("rule failed"))
+    }));
+    assert!(result.is_err());
+    assert_eq!(g.topology(), Topology::Bounded);
+    assert_eq!(g.get_flatten_grid(), vec![0; 4]);
+}
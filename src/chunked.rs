@@ -0,0 +1,448 @@
+use std::collections::HashMap;
+
+use crate::{Grid, GridErr, GridRead, GridWrite, MoveDirection, Offset, Pos};
+
+/// Represents an unbounded grid, the positions can be any row and col, even negative
+///
+/// The cells are stored on chunks of a fixed size, each one a `Grid`, a chunk is allocated
+/// on the first write of a value different from the empty value and dropped when all
+/// its cells are the empty value again, each chunk counts its filled cells so a write
+/// doesn't need to look at the other cells of the chunk
+///
+/// The methods follow the ones of `Grid`, so the game code can change between both
+///
+/// The grid doesn't implement `GridRead` and `GridWrite`, their algorithms allocate buffers
+/// of the size of the grid and a flood fill over an unbounded world would never end,
+/// `ChunkedGrid::view` and `ChunkedGrid::view_mut` give a bounded window that implements them
+///
+/// ```.rust
+/// let mut world = das_grid::ChunkedGrid::new((16, 16), (32., 32.), '.');
+/// world.set((-1000, 5000), 'T').unwrap();
+/// assert_eq!(world.get((-1000, 5000)), Ok(&'T'));
+/// assert_eq!(world.get((7, 7)), Ok(&'.'));
+/// assert_eq!(world.chunk_count(), 1);
+///
+/// world.set((-1000, 5000), '.').unwrap();
+/// assert_eq!(world.chunk_count(), 0);
+/// ```
+pub struct ChunkedGrid<T> {
+    chunk_size: (i32, i32),
+    cell_size: (f32, f32),
+    initial_value: T,
+    chunks: HashMap<Pos, Chunk<T>>,
+}
+
+/// Represents a bounded window of a chunked grid, see `ChunkedGrid::view`
+///
+/// The positions are local to the window, (0, 0) is the top left cell of the view
+pub struct ChunkedView<'a, T> {
+    grid: &'a ChunkedGrid<T>,
+    origin: Pos,
+    rows: i32,
+    cols: i32,
+}
+
+/// Represents a mutable bounded window of a chunked grid, see `ChunkedGrid::view_mut`
+///
+/// The writes go through `ChunkedGrid::replace`, so the chunks are allocated and dropped
+/// like on the grid itself
+pub struct ChunkedViewMut<'a, T> {
+    grid: &'a mut ChunkedGrid<T>,
+    origin: Pos,
+    rows: i32,
+    cols: i32,
+}
+
+// The cells of a chunk and the amount of them that aren't the empty value,
+// the amount is unknown (None) after a change through `ChunkedGrid::get_mut`
+struct Chunk<T> {
+    cells: Grid<T>,
+    filled: Option<usize>,
+}
+
+impl<T> ChunkedGrid<T> {
+    /// Creates an empty grid made of chunks of size rows x cols, every cell has the given value
+    ///
+    /// It panics if rows or cols aren't greater than 0, see `ChunkedGrid::try_new` for
+    /// the version which returns the error instead
+    pub fn new(chunk_size: (i32, i32), cell_size: (f32, f32), value: T) -> Self {
+        Self::try_new(chunk_size, cell_size, value).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates an empty grid made of chunks of size rows x cols, every cell has the given value
+    ///
+    /// If the rows or cols aren't greater than 0 it returns the error GridErr::InvalidSize
+    pub fn try_new(
+        chunk_size: (i32, i32),
+        cell_size: (f32, f32),
+        value: T,
+    ) -> Result<Self, GridErr> {
+        Grid::<T>::check_grid_size(chunk_size)?;
+        Ok(Self {
+            chunk_size,
+            cell_size,
+            initial_value: value,
+            chunks: HashMap::new(),
+        })
+    }
+
+    /// The size rows x cols of each chunk
+    pub fn chunk_size(&self) -> (i32, i32) {
+        self.chunk_size
+    }
+
+    /// The amount of chunks allocated
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Return the cell_size specified in grid creation
+    pub fn get_cell_size(&self) -> (f32, f32) {
+        self.cell_size
+    }
+
+    /// The empty value of the grid, the value of every cell never written
+    pub fn empty_value(&self) -> &T {
+        &self.initial_value
+    }
+
+    /// Gets the value of the position (row, col)
+    ///
+    /// There is no position out of the grid, the result keeps the signature of `Grid::get`
+    pub fn get(&self, src: impl Into<Pos>) -> Result<&T, GridErr> {
+        let (chunk, local) = self.locate(src.into());
+        match self.chunks.get(&chunk) {
+            Some(chunk) => chunk.cells.get(local),
+            None => Ok(&self.initial_value),
+        }
+    }
+
+    /// Gets the value of the position (row, col) as mutable, the chunk is allocated if needed
+    ///
+    /// The grid can't see the value given to the reference, so a chunk emptied through it
+    /// is only dropped on the next write to the chunk or by `ChunkedGrid::shrink`
+    ///
+    /// ```.rust
+    /// let mut world = das_grid::ChunkedGrid::new((4, 4), (1., 1.), 0);
+    /// *world.get_mut((9, 9)).unwrap() = 0;
+    /// assert_eq!(world.chunk_count(), 1);
+    /// world.shrink();
+    /// assert_eq!(world.chunk_count(), 0);
+    /// ```
+    pub fn get_mut(&mut self, src: impl Into<Pos>) -> Result<&mut T, GridErr>
+    where
+        T: Clone,
+    {
+        let (chunk, local) = self.locate(src.into());
+        let chunk = self.chunk_mut(chunk);
+        chunk.filled = None;
+        chunk.cells.get_mut(local)
+    }
+
+    /// Sets a given value to the position (row, col)
+    pub fn set(&mut self, dst: impl Into<Pos>, value: T) -> Result<(), GridErr>
+    where
+        T: Clone + PartialEq,
+    {
+        self.replace(dst, value)?;
+        Ok(())
+    }
+
+    /// Sets a given value to the position (row, col) and returns the previous value
+    ///
+    /// ```.rust
+    /// let mut world = das_grid::ChunkedGrid::new((8, 8), (1., 1.), 0);
+    /// assert_eq!(world.replace((-3, -3), 5), Ok(0));
+    /// assert_eq!(world.replace((-3, -3), 0), Ok(5));
+    /// assert_eq!(world.chunk_count(), 0);
+    /// ```
+    pub fn replace(&mut self, dst: impl Into<Pos>, value: T) -> Result<T, GridErr>
+    where
+        T: Clone + PartialEq,
+    {
+        let (key, local) = self.locate(dst.into());
+        let fills = value != self.initial_value;
+        if !fills && !self.chunks.contains_key(&key) {
+            return Ok(value);
+        }
+
+        let (chunk_size, cell_size, empty) = (self.chunk_size, self.cell_size, &self.initial_value);
+        let chunk = self
+            .chunks
+            .entry(key)
+            .or_insert_with(|| Chunk::new(chunk_size, cell_size, empty));
+        let prev = chunk.cells.replace(local, value)?;
+        let filled = match chunk.filled {
+            Some(filled) => filled + fills as usize - (prev != *empty) as usize,
+            None => chunk.count_filled(empty),
+        };
+
+        if filled == 0 {
+            self.chunks.remove(&key);
+        } else {
+            chunk.filled = Some(filled);
+        }
+        Ok(prev)
+    }
+
+    /// Takes the value out of the position (row, col) leaving the empty value of the grid in place
+    pub fn take(&mut self, src: impl Into<Pos>) -> Result<T, GridErr>
+    where
+        T: Clone + PartialEq,
+    {
+        let empty = self.initial_value.clone();
+        self.replace(src, empty)
+    }
+
+    /// Moves a given value from position (row, col) to destiny position (row, col)
+    ///
+    /// The source gets the empty value and the value that was on the destiny is returned
+    ///
+    /// ```.rust
+    /// let mut world = das_grid::ChunkedGrid::new((4, 4), (1., 1.), 0);
+    /// world.set((0, 0), 1).unwrap();
+    /// assert_eq!(world.mov((0, 0), (100, -100)), Ok(0));
+    /// assert_eq!(world.get((100, -100)), Ok(&1));
+    /// assert_eq!(world.chunk_count(), 1);
+    /// ```
+    pub fn mov(&mut self, src: impl Into<Pos>, dest: impl Into<Pos>) -> Result<T, GridErr>
+    where
+        T: Clone + PartialEq,
+    {
+        let (src, dest) = (src.into(), dest.into());
+        let prev = self.take(src)?;
+        self.replace(dest, prev)
+    }
+
+    /// Moves a given value from position (row, col) to another position based on the direction
+    pub fn mov_to(
+        &mut self,
+        src: impl Into<Pos>,
+        dst_direction: MoveDirection,
+    ) -> Result<T, GridErr>
+    where
+        T: Clone + PartialEq,
+    {
+        self.mov_by(src, dst_direction)
+    }
+
+    /// Moves a given value from position (row, col) to the position at the given offset
+    pub fn mov_by(&mut self, src: impl Into<Pos>, offset: impl Into<Offset>) -> Result<T, GridErr>
+    where
+        T: Clone + PartialEq,
+    {
+        let src = src.into();
+        self.mov(src, src + offset.into())
+    }
+
+    /// Creates a new grid which is a snapshot of the given position and size
+    ///
+    /// If the rows or cols aren't greater than 0 it returns the error GridErr::InvalidSize
+    ///
+    /// ```.rust
+    /// let mut world = das_grid::ChunkedGrid::new((4, 4), (1., 1.), 0);
+    /// world.set((-1, -1), 1).unwrap();
+    /// world.set((0, 0), 2).unwrap();
+    /// let view = world.get_subgrid((-1, -1), 2, 2).unwrap();
+    /// assert_eq!(view.get_flatten_grid(), vec![1, 0, 0, 2]);
+    /// ```
+    pub fn get_subgrid(&self, src: impl Into<Pos>, rows: i32, cols: i32) -> Result<Grid<T>, GridErr>
+    where
+        T: Clone,
+    {
+        let src = src.into();
+        let mut sub_grid = Grid::try_new((rows, cols), self.cell_size, self.initial_value.clone())?;
+        for (sub_index, subv) in sub_grid.positions().zip(sub_grid.cells.iter_mut()) {
+            *subv = self.get(src + Offset::from(sub_index))?.clone();
+        }
+
+        Ok(sub_grid)
+    }
+
+    /// Stamps the subgrid into the grid, the subgrid never bleeds out of an unbounded grid
+    pub fn stamp_subgrid(&mut self, dst: impl Into<Pos>, sub_grid: Grid<T>) -> Result<(), GridErr>
+    where
+        T: Clone + PartialEq,
+    {
+        let dst = dst.into();
+        for (sub_index, subv) in sub_grid.positions().zip(sub_grid.cells) {
+            self.set(dst + Offset::from(sub_index), subv)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the positions and values of the cells that aren't the empty value
+    pub fn occupied(&self) -> impl Iterator<Item = (Pos, &T)>
+    where
+        T: PartialEq,
+    {
+        self.chunks.iter().flat_map(move |(key, chunk)| {
+            let origin = Pos::new(key.row * self.chunk_size.0, key.col * self.chunk_size.1);
+            chunk
+                .cells
+                .positions()
+                .zip(chunk.cells.cells.iter())
+                .filter(move |(_, value)| **value != self.initial_value)
+                .map(move |(local, value)| (origin + Offset::from(local), value))
+        })
+    }
+
+    /// Drops the chunks emptied through `ChunkedGrid::get_mut`,
+    /// the other writes drop the empty chunks by themselves
+    pub fn shrink(&mut self)
+    where
+        T: PartialEq,
+    {
+        let empty = &self.initial_value;
+        self.chunks.retain(|_, chunk| {
+            let filled = chunk.filled.unwrap_or_else(|| chunk.count_filled(empty));
+            chunk.filled = Some(filled);
+            filled > 0
+        });
+    }
+
+    /// Creates a bounded window of the given position and size, the window implements `GridRead`
+    ///
+    /// If the rows or cols aren't greater than 0 it returns the error GridErr::InvalidSize
+    ///
+    /// ```.rust
+    /// use das_grid::{Connectivity, GridRead};
+    ///
+    /// let mut world = das_grid::ChunkedGrid::new((4, 4), (1., 1.), '.');
+    /// world.set((-1, 1), '#').unwrap();
+    /// let screen = world.view((-2, 0), 3, 3).unwrap();
+    /// assert_eq!(screen.get((1, 1)), Ok(&'#'));
+    /// let path = screen.bfs_path((1, 0), (1, 2), Connectivity::Four, |c| *c == '.').unwrap();
+    /// assert_eq!(path.cost, 4);
+    /// ```
+    pub fn view(
+        &self,
+        src: impl Into<Pos>,
+        rows: i32,
+        cols: i32,
+    ) -> Result<ChunkedView<'_, T>, GridErr> {
+        Grid::<T>::check_grid_size((rows, cols))?;
+        Ok(ChunkedView {
+            grid: self,
+            origin: src.into(),
+            rows,
+            cols,
+        })
+    }
+
+    /// Creates a mutable bounded window of the given position and size,
+    /// the window implements `GridRead` and `GridWrite`
+    ///
+    /// If the rows or cols aren't greater than 0 it returns the error GridErr::InvalidSize
+    ///
+    /// ```.rust
+    /// use das_grid::{Connectivity, GridWrite};
+    ///
+    /// let mut world = das_grid::ChunkedGrid::new((4, 4), (1., 1.), 0);
+    /// let mut room = world.view_mut((-3, -3), 3, 3).unwrap();
+    /// assert_eq!(room.flood_fill((0, 0), 1, |v| *v == 0, Connectivity::Four), Ok(9));
+    /// assert_eq!(world.get((-1, -1)), Ok(&1));
+    /// assert_eq!(world.get((0, 0)), Ok(&0));
+    /// ```
+    pub fn view_mut(
+        &mut self,
+        src: impl Into<Pos>,
+        rows: i32,
+        cols: i32,
+    ) -> Result<ChunkedViewMut<'_, T>, GridErr> {
+        Grid::<T>::check_grid_size((rows, cols))?;
+        Ok(ChunkedViewMut {
+            grid: self,
+            origin: src.into(),
+            rows,
+            cols,
+        })
+    }
+
+    // The chunk of the position and the position inside of the chunk
+    fn locate(&self, pos: Pos) -> (Pos, Pos) {
+        let (rows, cols) = self.chunk_size;
+        (
+            Pos::new(pos.row.div_euclid(rows), pos.col.div_euclid(cols)),
+            Pos::new(pos.row.rem_euclid(rows), pos.col.rem_euclid(cols)),
+        )
+    }
+
+    // The chunk as mutable, allocating it with the empty value if needed
+    fn chunk_mut(&mut self, key: Pos) -> &mut Chunk<T>
+    where
+        T: Clone,
+    {
+        let (chunk_size, cell_size, empty) = (self.chunk_size, self.cell_size, &self.initial_value);
+        self.chunks
+            .entry(key)
+            .or_insert_with(|| Chunk::new(chunk_size, cell_size, empty))
+    }
+}
+
+impl<T> Chunk<T> {
+    // A chunk with every cell holding the empty value
+    fn new(chunk_size: (i32, i32), cell_size: (f32, f32), empty: &T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: Grid::new(chunk_size, cell_size, empty.clone()),
+            filled: Some(0),
+        }
+    }
+
+    // Counts the cells that aren't the empty value
+    fn count_filled(&self, empty: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.cells
+            .cells
+            .iter()
+            .filter(|value| *value != empty)
+            .count()
+    }
+}
+
+impl<T> GridRead for ChunkedView<'_, T> {
+    type Item = T;
+
+    fn dimensions(&self) -> (i32, i32) {
+        (self.rows, self.cols)
+    }
+
+    fn get(&self, pos: impl Into<Pos>) -> Result<&T, GridErr> {
+        let pos = self.wrap(pos)?;
+        self.grid.get(self.origin + Offset::from(pos))
+    }
+}
+
+impl<T> GridRead for ChunkedViewMut<'_, T> {
+    type Item = T;
+
+    fn dimensions(&self) -> (i32, i32) {
+        (self.rows, self.cols)
+    }
+
+    fn get(&self, pos: impl Into<Pos>) -> Result<&T, GridErr> {
+        let pos = self.wrap(pos)?;
+        self.grid.get(self.origin + Offset::from(pos))
+    }
+}
+
+impl<T> GridWrite for ChunkedViewMut<'_, T>
+where
+    T: Clone + PartialEq,
+{
+    fn get_mut(&mut self, pos: impl Into<Pos>) -> Result<&mut T, GridErr> {
+        let pos = self.wrap(pos)?;
+        self.grid.get_mut(self.origin + Offset::from(pos))
+    }
+
+    fn set(&mut self, pos: impl Into<Pos>, value: T) -> Result<(), GridErr> {
+        let pos = self.wrap(pos)?;
+        self.grid.set(self.origin + Offset::from(pos), value)
+    }
+}
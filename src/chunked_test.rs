@@ -0,0 +1,185 @@
+#![allow(warnings, unused)]
+#[macro_use]
+use pretty_assertions::{assert_eq, assert_ne};
+
+use crate::{ChunkedGrid, Connectivity, Grid, GridErr, GridRead, GridWrite, MoveDirection, Pos};
+
+#[test]
+fn test_chunked_new() {
+    let g = ChunkedGrid::new((4, 8), (16., 16.), 0);
+    assert_eq!(g.chunk_size(), (4, 8));
+    assert_eq!(g.get_cell_size(), (16., 16.));
+    assert_eq!(g.empty_value(), &0);
+    assert_eq!(g.chunk_count(), 0);
+
+    assert_eq!(
+        ChunkedGrid::try_new((0, 8), (1., 1.), 0).err(),
+        Some(GridErr::InvalidSize { rows: 0, cols: 8 })
+    );
+}
+
+#[test]
+fn test_chunked_set_and_get() {
+    let mut g = ChunkedGrid::new((4, 4), (1., 1.), 0);
+    assert_eq!(g.get((i32::MIN, i32::MAX)), Ok(&0));
+
+    // Both sides of the chunk borders around the origin
+    for (i, pos) in [(-1, -1), (-1, 0), (0, -1), (0, 0), (3, 3), (4, 4)]
+        .iter()
+        .enumerate()
+    {
+        g.set(*pos, i + 1).unwrap();
+    }
+    assert_eq!(g.chunk_count(), 5);
+    assert_eq!(g.get((-1, -1)), Ok(&1));
+    assert_eq!(g.get((-1, 0)), Ok(&2));
+    assert_eq!(g.get((0, -1)), Ok(&3));
+    assert_eq!(g.get((0, 0)), Ok(&4));
+    assert_eq!(g.get((3, 3)), Ok(&5));
+    assert_eq!(g.get((4, 4)), Ok(&6));
+    assert_eq!(g.get((-4, -4)), Ok(&0));
+
+    // Writing the empty value doesn't allocate
+    g.set((100, 100), 0).unwrap();
+    assert_eq!(g.chunk_count(), 5);
+
+    // The chunk is dropped with its last value
+    assert_eq!(g.take((4, 4)), Ok(6));
+    assert_eq!(g.chunk_count(), 4);
+    g.set((0, 0), 0).unwrap();
+    assert_eq!(g.chunk_count(), 4);
+    g.set((3, 3), 0).unwrap();
+    assert_eq!(g.chunk_count(), 3);
+}
+
+#[test]
+fn test_chunked_get_mut_and_shrink() {
+    let mut g = ChunkedGrid::new((2, 2), (1., 1.), '.');
+    *g.get_mut((-5, 7)).unwrap() = '#';
+    assert_eq!(g.get((-5, 7)), Ok(&'#'));
+    assert_eq!(g.chunk_count(), 1);
+
+    *g.get_mut((-5, 7)).unwrap() = '.';
+    assert_eq!(g.chunk_count(), 1);
+    g.shrink();
+    assert_eq!(g.chunk_count(), 0);
+
+    // The next write recounts the chunk changed through get_mut
+    *g.get_mut((0, 0)).unwrap() = '#';
+    *g.get_mut((1, 1)).unwrap() = '#';
+    *g.get_mut((0, 0)).unwrap() = '.';
+    g.set((0, 1), '#').unwrap();
+    g.set((0, 1), '.').unwrap();
+    assert_eq!(g.chunk_count(), 1);
+    g.set((1, 1), '.').unwrap();
+    assert_eq!(g.chunk_count(), 0);
+}
+
+#[test]
+fn test_chunked_filled_count() {
+    let mut g = ChunkedGrid::new((3, 3), (1., 1.), 0);
+    for col in 0..3 {
+        g.set((0, col), 1).unwrap();
+    }
+    // Replacing a filled cell with another value keeps the count
+    g.set((0, 1), 2).unwrap();
+    g.set((0, 0), 0).unwrap();
+    g.set((0, 1), 0).unwrap();
+    assert_eq!(g.chunk_count(), 1);
+    assert_eq!(g.take((0, 2)), Ok(1));
+    assert_eq!(g.chunk_count(), 0);
+}
+
+#[test]
+fn test_chunked_mov() {
+    let mut g = ChunkedGrid::new((8, 8), (1., 1.), 0);
+    g.set((0, 0), 1).unwrap();
+    g.set((-20, 0), 2).unwrap();
+
+    assert_eq!(g.mov((0, 0), (-20, 0)), Ok(2));
+    assert_eq!(g.get((-20, 0)), Ok(&1));
+    assert_eq!(g.get((0, 0)), Ok(&0));
+    assert_eq!(g.chunk_count(), 1);
+
+    assert_eq!(g.mov_to((-20, 0), MoveDirection::UpLeft), Ok(0));
+    assert_eq!(g.get((-21, -1)), Ok(&1));
+    assert_eq!(g.mov_by((-21, -1), (21, 1)), Ok(0));
+    assert_eq!(g.get((0, 0)), Ok(&1));
+    assert_eq!(g.chunk_count(), 1);
+}
+
+#[test]
+fn test_chunked_subgrid() {
+    let mut g = ChunkedGrid::new((2, 2), (1., 1.), 0);
+    let stamp = Grid::new_from_vector((3, 3), (1., 1.), vec![1, 2, 3, 4, 0, 6, 7, 8, 9]);
+    g.stamp_subgrid((-2, -2), stamp).unwrap();
+    assert_eq!(g.chunk_count(), 4);
+    assert_eq!(g.get((-1, -1)), Ok(&0));
+    assert_eq!(g.get((0, 0)), Ok(&9));
+
+    let sub = g.get_subgrid((-3, -3), 4, 4).unwrap();
+    assert_eq!(
+        sub.get_flatten_grid(),
+        vec![0, 0, 0, 0, 0, 1, 2, 3, 0, 4, 0, 6, 0, 7, 8, 9]
+    );
+    assert_eq!(
+        g.get_subgrid((0, 0), 0, 1).err(),
+        Some(GridErr::InvalidSize { rows: 0, cols: 1 })
+    );
+
+    let mut occupied = g.occupied().map(|(p, v)| (p, *v)).collect::<Vec<_>>();
+    occupied.sort();
+    assert_eq!(occupied.len(), 8);
+    assert_eq!(occupied[0], (Pos::new(-2, -2), 1));
+    assert_eq!(occupied[7], (Pos::new(0, 0), 9));
+}
+
+#[test]
+fn test_chunked_view() {
+    let mut g = ChunkedGrid::new((2, 2), (1., 1.), '.');
+    for row in -3..1 {
+        g.set((row, 0), '#').unwrap();
+    }
+
+    let v = g.view((-3, -1), 4, 3).unwrap();
+    assert_eq!(v.dimensions(), (4, 3));
+    assert_eq!(v.get((0, 1)), Ok(&'#'));
+    assert_eq!(
+        v.get((4, 0)),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(4, 0)
+        })
+    );
+    assert_eq!(v.iter().filter(|(_, c)| **c == '#').count(), 4);
+    assert_eq!(
+        v.bfs_path((0, 0), (0, 2), Connectivity::Four, |c| *c == '.'),
+        None
+    );
+    assert_eq!(
+        g.view((0, 0), 0, 3).err(),
+        Some(GridErr::InvalidSize { rows: 0, cols: 3 })
+    );
+}
+
+#[test]
+fn test_chunked_view_mut() {
+    let mut g = ChunkedGrid::new((2, 2), (1., 1.), 0);
+    g.set((1, 2), 5).unwrap();
+
+    let mut v = g.view_mut((-1, -1), 3, 3).unwrap();
+    assert!(v.get((2, 3)).is_err());
+    assert_eq!(
+        v.flood_fill((1, 1), 1, |c| *c == 0, Connectivity::Four),
+        Ok(9)
+    );
+    assert_eq!(g.get((1, 1)), Ok(&1));
+    assert_eq!(g.get((1, 2)), Ok(&5));
+    assert_eq!(g.chunk_count(), 5);
+
+    // The writes through the view drop the emptied chunks
+    let mut v = g.view_mut((-1, -1), 3, 3).unwrap();
+    for pos in v.iter().map(|(pos, _)| pos).collect::<Vec<_>>() {
+        v.set(pos, 0).unwrap();
+    }
+    assert_eq!(g.chunk_count(), 1);
+}
@@ -0,0 +1,189 @@
+use parse_display_derive::Display;
+
+use crate::Offset;
+
+/// Represents the possible direction to move
+///
+/// MoveDirection::Right
+/// MoveDirection::Left
+/// MoveDirection::Up
+/// MoveDirection::Down
+/// MoveDirection::UpLeft
+/// MoveDirection::UpRight
+/// MoveDirection::DownLeft
+/// MoveDirection::DownRight
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+pub enum MoveDirection {
+    #[display("Right (0, 1)")]
+    Right,
+    #[display("Left (0, -1)")]
+    Left,
+    #[display("Up (-1, 0)")]
+    Up,
+    #[display("Down (1, 0)")]
+    Down,
+    #[display("UpLeft (-1, -1)")]
+    UpLeft,
+    #[display("UpRight (-1, 1)")]
+    UpRight,
+    #[display("DownLeft (1, -1)")]
+    DownLeft,
+    #[display("DownRight (1, 1)")]
+    DownRight,
+}
+
+/// Represent move to right position on Das Grid (0, 1)
+pub const MOVE_RIGHT: (i32, i32) = (0, 1);
+
+/// Represent move to left position on Das Grid (0, -1)
+pub const MOVE_LEFT: (i32, i32) = (0, -1);
+
+/// Represent move to up position on Das Grid (-1, 0)
+pub const MOVE_UP: (i32, i32) = (-1, 0);
+
+/// Represent move to down position on Das Grid (1, 0)
+pub const MOVE_DOWN: (i32, i32) = (1, 0);
+
+/// Represent move to up left position on Das Grid (-1, -1)
+pub const MOVE_UP_LEFT: (i32, i32) = (-1, -1);
+
+/// Represent move to up right position on Das Grid (-1, 1)
+pub const MOVE_UP_RIGHT: (i32, i32) = (-1, 1);
+
+/// Represent move to down left position on Das Grid (1, -1)
+pub const MOVE_DOWN_LEFT: (i32, i32) = (1, -1);
+
+/// Represent move to down right position on Das Grid (1, 1)
+pub const MOVE_DOWN_RIGHT: (i32, i32) = (1, 1);
+
+impl MoveDirection {
+    /// The eight compass directions in clockwise order starting at Up
+    ///
+    /// ```.rust
+    /// use das_grid::MoveDirection;
+    ///
+    /// for direction in MoveDirection::ALL {
+    ///     assert_eq!(direction.rotate_cw_45().rotate_ccw_45(), direction);
+    /// }
+    /// ```
+    pub const ALL: [MoveDirection; 8] = [
+        MoveDirection::Up,
+        MoveDirection::UpRight,
+        MoveDirection::Right,
+        MoveDirection::DownRight,
+        MoveDirection::Down,
+        MoveDirection::DownLeft,
+        MoveDirection::Left,
+        MoveDirection::UpLeft,
+    ];
+
+    /// The four orthogonal directions in clockwise order starting at Up
+    pub const ORTHOGONAL: [MoveDirection; 4] = [
+        MoveDirection::Up,
+        MoveDirection::Right,
+        MoveDirection::Down,
+        MoveDirection::Left,
+    ];
+
+    /// The four diagonal directions in clockwise order starting at UpRight
+    pub const DIAGONAL: [MoveDirection; 4] = [
+        MoveDirection::UpRight,
+        MoveDirection::DownRight,
+        MoveDirection::DownLeft,
+        MoveDirection::UpLeft,
+    ];
+
+    /// The offset of (row, col) that the direction moves on the grid
+    ///
+    /// ```.rust
+    /// use das_grid::{MoveDirection, Offset};
+    ///
+    /// assert_eq!(MoveDirection::Right.offset(), Offset::new(0, 1));
+    /// assert_eq!(Offset::from(MoveDirection::Up), Offset::new(-1, 0));
+    /// ```
+    pub fn offset(&self) -> Offset {
+        let (row, col) = match self {
+            MoveDirection::Up => MOVE_UP,
+            MoveDirection::Down => MOVE_DOWN,
+            MoveDirection::Left => MOVE_LEFT,
+            MoveDirection::Right => MOVE_RIGHT,
+            MoveDirection::UpLeft => MOVE_UP_LEFT,
+            MoveDirection::UpRight => MOVE_UP_RIGHT,
+            MoveDirection::DownLeft => MOVE_DOWN_LEFT,
+            MoveDirection::DownRight => MOVE_DOWN_RIGHT,
+        };
+        Offset::new(row, col)
+    }
+
+    /// Returns true for Up, Right, Down and Left
+    pub fn is_orthogonal(&self) -> bool {
+        MoveDirection::ORTHOGONAL.contains(self)
+    }
+
+    /// Returns true for UpLeft, UpRight, DownLeft and DownRight
+    pub fn is_diagonal(&self) -> bool {
+        !self.is_orthogonal()
+    }
+
+    /// The direction pointing the other way
+    ///
+    /// ```.rust
+    /// use das_grid::MoveDirection;
+    ///
+    /// assert_eq!(MoveDirection::UpLeft.opposite(), MoveDirection::DownRight);
+    /// ```
+    pub fn opposite(&self) -> MoveDirection {
+        self.rotate(4)
+    }
+
+    /// The direction rotated 90 degrees clockwise
+    ///
+    /// ```.rust
+    /// use das_grid::MoveDirection;
+    ///
+    /// assert_eq!(MoveDirection::Up.rotate_cw(), MoveDirection::Right);
+    /// assert_eq!(MoveDirection::UpRight.rotate_cw(), MoveDirection::DownRight);
+    /// ```
+    pub fn rotate_cw(&self) -> MoveDirection {
+        self.rotate(2)
+    }
+
+    /// The direction rotated 90 degrees counter-clockwise
+    ///
+    /// ```.rust
+    /// use das_grid::MoveDirection;
+    ///
+    /// assert_eq!(MoveDirection::Up.rotate_ccw(), MoveDirection::Left);
+    /// ```
+    pub fn rotate_ccw(&self) -> MoveDirection {
+        self.rotate(-2)
+    }
+
+    /// The direction rotated 45 degrees clockwise
+    ///
+    /// ```.rust
+    /// use das_grid::MoveDirection;
+    ///
+    /// assert_eq!(MoveDirection::Up.rotate_cw_45(), MoveDirection::UpRight);
+    /// ```
+    pub fn rotate_cw_45(&self) -> MoveDirection {
+        self.rotate(1)
+    }
+
+    /// The direction rotated 45 degrees counter-clockwise
+    ///
+    /// ```.rust
+    /// use das_grid::MoveDirection;
+    ///
+    /// assert_eq!(MoveDirection::Up.rotate_ccw_45(), MoveDirection::UpLeft);
+    /// ```
+    pub fn rotate_ccw_45(&self) -> MoveDirection {
+        self.rotate(-1)
+    }
+
+    // Rotates by steps of 45 degrees, positive steps are clockwise
+    fn rotate(&self, steps: i32) -> MoveDirection {
+        let idx = MoveDirection::ALL.iter().position(|d| d == self).unwrap() as i32;
+        MoveDirection::ALL[(idx + steps).rem_euclid(8) as usize]
+    }
+}
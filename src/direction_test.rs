@@ -0,0 +1,114 @@
+#![allow(warnings, unused)]
+#[macro_use]
+use pretty_assertions::{assert_eq, assert_ne};
+
+use std::collections::HashSet;
+
+use crate::{Grid, GridErr, MoveDirection, Offset, Pos};
+
+#[test]
+fn test_direction_offsets() {
+    let offsets = MoveDirection::ALL
+        .iter()
+        .map(|d| d.offset())
+        .collect::<HashSet<_>>();
+    assert_eq!(offsets.len(), 8);
+    assert!(offsets.iter().all(|o| o.chebyshev() == 1));
+
+    assert_eq!(MoveDirection::UpLeft.offset(), Offset::new(-1, -1));
+    assert_eq!(MoveDirection::UpRight.offset(), Offset::new(-1, 1));
+    assert_eq!(MoveDirection::DownLeft.offset(), Offset::new(1, -1));
+    assert_eq!(MoveDirection::DownRight.offset(), Offset::new(1, 1));
+
+    assert!(MoveDirection::ORTHOGONAL.iter().all(|d| d.is_orthogonal()));
+    assert!(MoveDirection::DIAGONAL.iter().all(|d| d.is_diagonal()));
+    assert!(MoveDirection::DIAGONAL
+        .iter()
+        .all(|d| d.offset().manhattan() == 2));
+}
+
+#[test]
+fn test_direction_rotation() {
+    assert_eq!(MoveDirection::Right.rotate_cw(), MoveDirection::Down);
+    assert_eq!(MoveDirection::Left.rotate_cw(), MoveDirection::Up);
+    assert_eq!(MoveDirection::Right.rotate_ccw(), MoveDirection::Up);
+    assert_eq!(MoveDirection::UpLeft.rotate_cw_45(), MoveDirection::Up);
+    assert_eq!(MoveDirection::UpLeft.rotate_ccw_45(), MoveDirection::Left);
+    assert_eq!(
+        MoveDirection::DownRight.rotate_ccw(),
+        MoveDirection::UpRight
+    );
+
+    for direction in MoveDirection::ALL {
+        assert_eq!(direction.rotate_cw().rotate_ccw(), direction);
+        assert_eq!(direction.opposite().opposite(), direction);
+        assert_eq!(direction.opposite().offset(), -direction.offset());
+        assert_eq!(direction.rotate_cw().rotate_cw(), direction.opposite());
+
+        // Rotating the offset (row, col) clockwise gives (col, -row)
+        let offset = direction.offset();
+        assert_eq!(
+            direction.rotate_cw().offset(),
+            Offset::new(offset.col, -offset.row)
+        );
+    }
+}
+
+#[test]
+fn test_mov_to_diagonals() {
+    let mut g = Grid::new((3, 3), (1., 1.), 0);
+    g.set((1, 1), 1);
+
+    assert_eq!(g.mov_to((1, 1), MoveDirection::UpLeft), Ok(0));
+    assert_eq!(g.get((0, 0)).unwrap(), &1);
+    assert_eq!(
+        g.mov_to((0, 0), MoveDirection::UpLeft),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(-1, -1)
+        })
+    );
+    assert_eq!(g.mov_to((0, 0), MoveDirection::DownRight), Ok(0));
+    assert_eq!(g.mov_to((1, 1), MoveDirection::DownLeft), Ok(0));
+    assert_eq!(g.get((2, 0)).unwrap(), &1);
+    assert_eq!(g.mov_to((2, 0), MoveDirection::UpRight), Ok(0));
+    assert_eq!(g.get((1, 1)).unwrap(), &1);
+    assert_eq!(g.get_flatten_grid().iter().sum::<i32>(), 1);
+}
+
+#[test]
+fn test_mov_by() {
+    let mut g = Grid::new((8, 8), (1., 1.), 0);
+    g.set((7, 1), 1);
+
+    assert_eq!(g.mov_by((7, 1), (-2, 1)), Ok(0));
+    assert_eq!(g.get((5, 2)).unwrap(), &1);
+    assert_eq!(g.mov_by(Pos::new(5, 2), Offset::new(-1, -2)), Ok(0));
+    assert_eq!(g.get((4, 0)).unwrap(), &1);
+    assert_eq!(
+        g.mov_by((4, 0), (1, -2)),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(5, -2)
+        })
+    );
+    assert_eq!(
+        g.mov_by((8, 0), (-1, 0)),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(8, 0)
+        })
+    );
+    assert_eq!(g.get((4, 0)).unwrap(), &1);
+
+    g.set((6, 1), 2);
+    let rule_empty = |_: (i32, i32), value: &i32| -> Result<(), GridErr> {
+        if *value != 0 {
+            return Err(GridErr::RuleFailed(None));
+        }
+        Ok(())
+    };
+    assert_eq!(
+        g.mov_by_with_rules((4, 0), (2, 1), vec![rule_empty]),
+        Err(GridErr::RuleFailed(None))
+    );
+    assert_eq!(g.mov_by_with_rules((4, 0), (2, 2), vec![rule_empty]), Ok(0));
+    assert_eq!(g.get((6, 2)).unwrap(), &1);
+}
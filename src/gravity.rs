@@ -0,0 +1,133 @@
+use crate::{Grid, GridErr, MoveDirection};
+
+impl<T> Grid<T> {
+    /// Compacts every col or row toward the direction, like the gravity pulling the values,
+    /// the values keep their order and the empty cells go to the opposite side
+    ///
+    /// Up and Down compacts the cols, Left and Right compacts the rows
+    ///
+    /// Returns the amount of values that changed of cell
+    ///
+    /// If the direction is a diagonal it return an error of GridErr::InvalidDirection
+    ///
+    /// ```.rust
+    /// let mut grid = das_grid::Grid::new_from_vector((3, 2), (1., 1.), vec![1, 0, 0, 2, 3, 0]);
+    /// let moved = grid.apply_gravity(das_grid::MoveDirection::Down, |v| *v == 0);
+    /// assert_eq!(moved, Ok(2));
+    /// assert_eq!(grid.get_flatten_grid(), vec![0, 0, 1, 0, 3, 2]);
+    /// ```
+    pub fn apply_gravity<F>(
+        &mut self,
+        direction: MoveDirection,
+        is_empty: F,
+    ) -> Result<usize, GridErr>
+    where
+        T: Clone,
+        F: Fn(&T) -> bool,
+    {
+        let (on_cols, toward_end) = match direction {
+            MoveDirection::Up => (true, false),
+            MoveDirection::Down => (true, true),
+            MoveDirection::Left => (false, false),
+            MoveDirection::Right => (false, true),
+            _ => return Err(GridErr::InvalidDirection { direction }),
+        };
+
+        let mut moved = 0;
+        let lines = if on_cols { self.cols } else { self.rows };
+        for idx in 0..lines {
+            let line = if on_cols {
+                self.get_col(idx)
+            } else {
+                self.get_row(idx)
+            };
+            let Ok(line) = line else { continue };
+
+            let (line, line_moved) = compact(line, toward_end, &is_empty);
+            moved += line_moved;
+            let _ = if on_cols {
+                self.set_col(idx, line)
+            } else {
+                self.set_row(idx, line)
+            };
+        }
+
+        Ok(moved)
+    }
+
+    /// Removes the rows where every cell matches the predicate, the rows above
+    /// fall to take their place and the empty value fills the top of the grid
+    ///
+    /// Returns the indices of the cleared rows, from top to bottom
+    ///
+    /// ```.rust
+    /// let mut grid = das_grid::Grid::new_from_vector(
+    ///     (3, 3),
+    ///     (1., 1.),
+    ///     vec![0, 1, 0, 1, 1, 1, 1, 0, 1],
+    /// );
+    /// assert_eq!(grid.clear_full_rows(|v| *v != 0), vec![1]);
+    /// assert_eq!(grid.get_flatten_grid(), vec![0, 0, 0, 0, 1, 0, 1, 0, 1]);
+    /// ```
+    pub fn clear_full_rows<F>(&mut self, pred: F) -> Vec<i32>
+    where
+        T: Clone,
+        F: Fn(&T) -> bool,
+    {
+        let mut cleared = vec![];
+        let mut kept = vec![];
+        for row in 0..self.rows {
+            let Ok(values) = self.get_row(row) else {
+                continue;
+            };
+            if values.iter().all(&pred) {
+                cleared.push(row);
+            } else {
+                kept.push(values);
+            }
+        }
+
+        if cleared.is_empty() {
+            return cleared;
+        }
+
+        let empty_rows = cleared.len() as i32;
+        for row in 0..empty_rows {
+            let _ = self.set_row(row, vec![self.initial_value.clone(); self.cols as usize]);
+        }
+        for (row, values) in (empty_rows..self.rows).zip(kept) {
+            let _ = self.set_row(row, values);
+        }
+
+        cleared
+    }
+}
+
+// Moves the values that aren't empty to one end of the line keeping their order,
+// returns the new line and how many values changed of index
+fn compact<T, F>(line: Vec<T>, toward_end: bool, is_empty: &F) -> (Vec<T>, usize)
+where
+    F: Fn(&T) -> bool,
+{
+    let len = line.len();
+    let (empty, values): (Vec<_>, Vec<_>) =
+        line.into_iter().enumerate().partition(|(_, v)| is_empty(v));
+
+    let start = if toward_end { len - values.len() } else { 0 };
+    let moved = values
+        .iter()
+        .enumerate()
+        .filter(|(i, (idx, _))| start + i != *idx)
+        .count();
+
+    let (empty, values) = (
+        empty.into_iter().map(|(_, v)| v),
+        values.into_iter().map(|(_, v)| v),
+    );
+    let line = if toward_end {
+        empty.chain(values).collect()
+    } else {
+        values.chain(empty).collect()
+    };
+    (line, moved)
+}
@@ -0,0 +1,122 @@
+#![allow(warnings, unused)]
+#[macro_use]
+use pretty_assertions::{assert_eq, assert_ne};
+
+use crate::{Grid, GridErr, MoveDirection, Pos};
+
+#[test]
+fn test_apply_gravity() {
+    let mut g = Grid::new_from_vector(
+        (4, 3),
+        (1., 1.),
+        ["a.b", "..c", "d..", ".e."].concat().chars().collect(),
+    );
+    g.set_empty_value('.');
+    assert_eq!(g.apply_gravity(MoveDirection::Down, |c| *c == '.'), Ok(4));
+    assert_eq!(
+        g.get_flatten_grid().into_iter().collect::<String>(),
+        ["...", "...", "a.b", "dec"].concat()
+    );
+    assert_eq!(g.apply_gravity(MoveDirection::Down, |c| *c == '.'), Ok(0));
+
+    assert_eq!(g.apply_gravity(MoveDirection::Right, |c| *c == '.'), Ok(1));
+    assert_eq!(
+        g.get_flatten_grid().into_iter().collect::<String>(),
+        ["...", "...", ".ab", "dec"].concat()
+    );
+
+    assert_eq!(g.apply_gravity(MoveDirection::Up, |c| *c == '.'), Ok(5));
+    assert_eq!(
+        g.get_flatten_grid().into_iter().collect::<String>(),
+        ["dab", ".ec", "...", "..."].concat()
+    );
+
+    assert_eq!(g.apply_gravity(MoveDirection::Left, |c| *c == '.'), Ok(2));
+    assert_eq!(
+        g.get_flatten_grid().into_iter().collect::<String>(),
+        ["dab", "ec.", "...", "..."].concat()
+    );
+}
+
+#[test]
+fn test_apply_gravity_diagonal() {
+    let mut g = Grid::new_from_vector(
+        (4, 3),
+        (1., 1.),
+        ["a.b", "..c", "d..", ".e."].concat().chars().collect(),
+    );
+    g.set_empty_value('.');
+    assert_eq!(
+        g.apply_gravity(MoveDirection::DownLeft, |c| *c == '.'),
+        Err(GridErr::InvalidDirection {
+            direction: MoveDirection::DownLeft
+        })
+    );
+    assert_eq!(
+        g.get_flatten_grid().into_iter().collect::<String>(),
+        ["a.b", "..c", "d..", ".e."].concat()
+    );
+}
+
+#[test]
+fn test_clear_full_rows() {
+    let mut g = Grid::new_from_vector(
+        (5, 3),
+        (1., 1.),
+        "\
+        ..x\
+        xxx\
+        x.x\
+        xxx\
+        xxx\
+        "
+        .chars()
+        .collect(),
+    );
+    g.set_empty_value('.');
+
+    assert_eq!(g.clear_full_rows(|c| *c == 'x'), vec![1, 3, 4]);
+    assert_eq!(
+        g.get_flatten_grid().into_iter().collect::<String>(),
+        ["...", "...", "...", "..x", "x.x"].concat()
+    );
+    assert_eq!(g.clear_full_rows(|c| *c == 'x'), vec![]);
+    assert_eq!(
+        g.get_flatten_grid().into_iter().collect::<String>(),
+        ["...", "...", "...", "..x", "x.x"].concat()
+    );
+}
+
+#[test]
+fn test_set_row_and_col() {
+    let mut g = Grid::new_from_vector(
+        (4, 3),
+        (1., 1.),
+        ["a.b", "..c", "d..", ".e."].concat().chars().collect(),
+    );
+    g.set_empty_value('.');
+    assert_eq!(g.set_row(0, vec!['x', 'y', 'z']), Ok(()));
+    assert_eq!(g.set_col(2, vec!['1', '2', '3', '4']), Ok(()));
+    assert_eq!(
+        g.get_flatten_grid().into_iter().collect::<String>(),
+        ["xy1", "..2", "d.3", ".e4"].concat()
+    );
+
+    assert_eq!(
+        g.set_row(4, vec!['x', 'y', 'z']),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(4, 0)
+        })
+    );
+    assert_eq!(
+        g.set_col(0, vec!['x']),
+        Err(GridErr::SizeMismatch {
+            expected: 4,
+            actual: 1
+        })
+    );
+    assert_eq!(
+        g.get_flatten_grid().into_iter().collect::<String>(),
+        ["xy1", "..2", "d.3", ".e4"].concat()
+    );
+}
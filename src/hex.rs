@@ -0,0 +1,424 @@
+use std::{
+    fmt,
+    ops::{Add, Mul, Sub},
+};
+
+use crate::{Grid, GridErr, Pos};
+
+/// Represents a hex in axial coordinates, q grows to the east and r to the south
+///
+/// The hexes are pointy topped, a `Pos` is the offset coordinate of the hex
+/// where the odd rows are shoved half hex to the east ("odd-r" layout)
+///
+/// ```.rust
+/// use das_grid::{Axial, Cube, Pos};
+///
+/// let hex = Axial::from(Pos::new(3, 2));
+/// assert_eq!(hex, Axial::new(1, 3));
+/// assert_eq!(hex.to_offset(), Pos::new(3, 2));
+/// assert_eq!(Cube::from(hex), Cube::new(1, 3, -4));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Axial {
+    pub q: i32,
+    pub r: i32,
+}
+
+/// Represents a hex in cube coordinates, where q + r + s is always 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Cube {
+    pub q: i32,
+    pub r: i32,
+    pub s: i32,
+}
+
+/// Represents the six directions of a pointy topped hex
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexDirection {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+
+impl HexDirection {
+    /// The six directions, starting on the east and going counterclockwise
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::East,
+        HexDirection::NorthEast,
+        HexDirection::NorthWest,
+        HexDirection::West,
+        HexDirection::SouthWest,
+        HexDirection::SouthEast,
+    ];
+
+    /// The axial offset of one step to the direction
+    pub fn offset(&self) -> Axial {
+        match self {
+            HexDirection::East => Axial::new(1, 0),
+            HexDirection::NorthEast => Axial::new(1, -1),
+            HexDirection::NorthWest => Axial::new(0, -1),
+            HexDirection::West => Axial::new(-1, 0),
+            HexDirection::SouthWest => Axial::new(-1, 1),
+            HexDirection::SouthEast => Axial::new(0, 1),
+        }
+    }
+
+    /// The direction pointing to the other side
+    pub fn opposite(&self) -> HexDirection {
+        self.rotate(3)
+    }
+
+    /// The next direction clockwise
+    pub fn rotate_cw(&self) -> HexDirection {
+        self.rotate(5)
+    }
+
+    /// The next direction counterclockwise
+    pub fn rotate_ccw(&self) -> HexDirection {
+        self.rotate(1)
+    }
+
+    // Turns counterclockwise the given sixths of a turn
+    fn rotate(&self, sixths: usize) -> HexDirection {
+        let idx = HexDirection::ALL.iter().position(|d| d == self).unwrap();
+        HexDirection::ALL[(idx + sixths) % 6]
+    }
+}
+
+impl Axial {
+    /// Creates a hex with the axial coordinates
+    pub fn new(q: i32, r: i32) -> Self {
+        Self { q, r }
+    }
+
+    /// The offset coordinate of the hex, where it is stored on the grid
+    pub fn to_offset(&self) -> Pos {
+        Pos::new(self.r, self.q + (self.r - (self.r & 1)) / 2)
+    }
+
+    /// The hex one step to the direction
+    pub fn neighbor(&self, direction: HexDirection) -> Axial {
+        *self + direction.offset()
+    }
+
+    /// The six hexes around, in the order of `HexDirection::ALL`
+    pub fn neighbors(&self) -> [Axial; 6] {
+        HexDirection::ALL.map(|direction| self.neighbor(direction))
+    }
+
+    /// The amount of steps to the other hex
+    ///
+    /// ```.rust
+    /// use das_grid::Axial;
+    ///
+    /// assert_eq!(Axial::new(0, 0).distance(Axial::new(2, -3)), 3);
+    /// ```
+    pub fn distance(&self, other: Axial) -> i32 {
+        Cube::from(*self).distance(Cube::from(other))
+    }
+
+    /// The hexes at exactly the radius, starting on the south west and going counterclockwise
+    ///
+    /// A radius of 0 is the hex itself and a radius of n has 6 x n hexes
+    pub fn ring(&self, radius: i32) -> Vec<Axial> {
+        if radius <= 0 {
+            return vec![*self];
+        }
+
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+        let mut hex = *self + HexDirection::SouthWest.offset() * radius;
+        for direction in HexDirection::ALL {
+            for _ in 0..radius {
+                ring.push(hex);
+                hex = hex.neighbor(direction);
+            }
+        }
+        ring
+    }
+
+    /// The hexes up to the radius, the hex itself and then each ring from the inside out
+    ///
+    /// ```.rust
+    /// use das_grid::Axial;
+    ///
+    /// let spiral = Axial::new(0, 0).spiral(2);
+    /// assert_eq!(spiral.len(), 19);
+    /// assert_eq!(spiral[0], Axial::new(0, 0));
+    /// ```
+    pub fn spiral(&self, radius: i32) -> Vec<Axial> {
+        (0..=radius.max(0)).flat_map(|r| self.ring(r)).collect()
+    }
+}
+
+impl Cube {
+    /// Creates a hex with the cube coordinates
+    ///
+    /// It panics if q + r + s isn't 0
+    pub fn new(q: i32, r: i32, s: i32) -> Self {
+        assert_eq!(q + r + s, 0, "the cube coordinates must sum 0");
+        Self { q, r, s }
+    }
+
+    /// The amount of steps to the other hex
+    pub fn distance(&self, other: Cube) -> i32 {
+        (self.q - other.q)
+            .abs()
+            .max((self.r - other.r).abs())
+            .max((self.s - other.s).abs())
+    }
+
+    // The nearest hex of fractional cube coordinates
+    fn round(q: f32, r: f32, s: f32) -> Cube {
+        let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+        if dq > dr && dq > ds {
+            rq = -rr - rs;
+        } else if dr > ds {
+            rr = -rq - rs;
+        }
+        let (q, r) = (rq as i32, rr as i32);
+        Cube { q, r, s: -q - r }
+    }
+}
+
+impl From<Cube> for Axial {
+    fn from(cube: Cube) -> Self {
+        Axial::new(cube.q, cube.r)
+    }
+}
+
+impl From<Axial> for Cube {
+    fn from(axial: Axial) -> Self {
+        Cube {
+            q: axial.q,
+            r: axial.r,
+            s: -axial.q - axial.r,
+        }
+    }
+}
+
+impl From<Pos> for Axial {
+    fn from(pos: Pos) -> Self {
+        Axial::new(pos.col - (pos.row - (pos.row & 1)) / 2, pos.row)
+    }
+}
+
+impl From<Axial> for Pos {
+    fn from(axial: Axial) -> Self {
+        axial.to_offset()
+    }
+}
+
+impl fmt::Display for Axial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(q: {} r: {})", self.q, self.r)
+    }
+}
+
+impl Add for Axial {
+    type Output = Axial;
+
+    fn add(self, other: Axial) -> Axial {
+        Axial::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for Axial {
+    type Output = Axial;
+
+    fn sub(self, other: Axial) -> Axial {
+        Axial::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Mul<i32> for Axial {
+    type Output = Axial;
+
+    fn mul(self, n: i32) -> Axial {
+        Axial::new(self.q * n, self.r * n)
+    }
+}
+
+/// Represents a grid of pointy topped hexes
+///
+/// The hexes are stored on a `Grid` by their offset coordinates, the rows x cols
+/// of the grid, where the odd rows are shoved half hex to the east
+///
+/// The methods take any coordinate that converts into `Axial`, like a `Cube` or a `Pos`
+///
+/// ```.rust
+/// use das_grid::{Axial, HexDirection, HexGrid, Pos};
+///
+/// let mut map = HexGrid::new((5, 5), (32., 28.), '.');
+/// map.set(Pos::new(2, 2), 'A').unwrap();
+/// assert_eq!(map.mov_to(Pos::new(2, 2), HexDirection::SouthEast), Ok('.'));
+/// assert_eq!(map.get(Pos::new(3, 2)), Ok(&'A'));
+/// assert_eq!(map.neighbors(Pos::new(0, 0)).count(), 2);
+/// assert_eq!(map.neighbors(Pos::new(2, 2)).count(), 6);
+/// ```
+pub struct HexGrid<T> {
+    grid: Grid<T>,
+}
+
+impl<T> HexGrid<T> {
+    /// Creates a hex grid of size rows x cols with the value on each hex
+    ///
+    /// The cell size is the height and the width of a hex, in the order of the frame size
+    ///
+    /// It panics if rows or cols aren't greater than 0
+    pub fn new(frame_size: (i32, i32), cell_size: (f32, f32), value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_grid(Grid::new(frame_size, cell_size, value))
+    }
+
+    /// Creates a hex grid with the cells of the grid as the offset coordinates
+    pub fn from_grid(grid: Grid<T>) -> Self {
+        Self { grid }
+    }
+
+    /// The grid storing the hexes by offset coordinates
+    pub fn as_grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    /// The grid storing the hexes by offset coordinates as mutable
+    pub fn as_grid_mut(&mut self) -> &mut Grid<T> {
+        &mut self.grid
+    }
+
+    /// Returns the grid storing the hexes by offset coordinates
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+
+    /// Gets the value of the hex
+    ///
+    /// If the hex is out of the grid it return an error of GridErr::OutOfGrid
+    /// with the offset coordinate
+    pub fn get(&self, hex: impl Into<Axial>) -> Result<&T, GridErr> {
+        self.grid.get(hex.into().to_offset())
+    }
+
+    /// Gets the value of the hex as mutable
+    ///
+    /// If the hex is out of the grid it return an error of GridErr::OutOfGrid
+    pub fn get_mut(&mut self, hex: impl Into<Axial>) -> Result<&mut T, GridErr> {
+        self.grid.get_mut(hex.into().to_offset())
+    }
+
+    /// Sets the value of the hex
+    ///
+    /// If the hex is out of the grid it return an error of GridErr::OutOfGrid
+    pub fn set(&mut self, hex: impl Into<Axial>, value: T) -> Result<(), GridErr> {
+        self.grid.set(hex.into().to_offset(), value)
+    }
+
+    /// Moves the value of a hex to another, as `Grid::mov` does
+    ///
+    /// Returns the value that was on the destiny, or an error of GridErr::OutOfGrid
+    pub fn mov(&mut self, src: impl Into<Axial>, dst: impl Into<Axial>) -> Result<T, GridErr>
+    where
+        T: Clone,
+    {
+        self.grid
+            .mov(src.into().to_offset(), dst.into().to_offset())
+    }
+
+    /// Moves the value of a hex one step to the direction
+    ///
+    /// Returns the value that was on the destiny, or an error of GridErr::OutOfGrid
+    pub fn mov_to(&mut self, src: impl Into<Axial>, direction: HexDirection) -> Result<T, GridErr>
+    where
+        T: Clone,
+    {
+        let src = src.into();
+        self.mov(src, src.neighbor(direction))
+    }
+
+    /// Returns the six neighbors of the hex with their values,
+    /// the hexes out of the grid are skipped
+    pub fn neighbors(&self, hex: impl Into<Axial>) -> impl Iterator<Item = (Axial, &T)> {
+        self.cells_at(hex.into().neighbors())
+    }
+
+    /// Returns the hexes at exactly the radius with their values,
+    /// the hexes out of the grid are skipped
+    pub fn ring(&self, center: impl Into<Axial>, radius: i32) -> impl Iterator<Item = (Axial, &T)> {
+        self.cells_at(center.into().ring(radius))
+    }
+
+    /// Returns the hexes up to the radius with their values, from the inside out,
+    /// the hexes out of the grid are skipped
+    ///
+    /// ```.rust
+    /// use das_grid::{HexGrid, Pos};
+    ///
+    /// let map = HexGrid::new((9, 9), (1., 1.), 0);
+    /// assert_eq!(map.spiral(Pos::new(4, 4), 2).count(), 19);
+    /// assert_eq!(map.spiral(Pos::new(0, 0), 1).count(), 3);
+    /// ```
+    pub fn spiral(
+        &self,
+        center: impl Into<Axial>,
+        radius: i32,
+    ) -> impl Iterator<Item = (Axial, &T)> {
+        self.cells_at(center.into().spiral(radius))
+    }
+
+    /// The amount of steps between two hexes
+    pub fn distance(&self, a: impl Into<Axial>, b: impl Into<Axial>) -> i32 {
+        a.into().distance(b.into())
+    }
+
+    /// The center of the hex in pixels, the hex (0, 0) is centered on (0., 0.)
+    ///
+    /// The pixels follow the order (row, col) of the cell size, as `Grid::enumerate_to_cell_size`
+    ///
+    /// ```.rust
+    /// use das_grid::{Axial, HexGrid, Pos};
+    ///
+    /// let map = HexGrid::new((4, 4), (40., 30.), 0);
+    /// assert_eq!(map.hex_to_pixel(Pos::new(1, 0)), (30., 15.));
+    /// assert_eq!(map.pixel_to_hex((30., 15.)), Axial::from(Pos::new(1, 0)));
+    /// assert_eq!(map.pixel_to_hex((5., 12.)), Axial::new(0, 0));
+    /// ```
+    pub fn hex_to_pixel(&self, hex: impl Into<Axial>) -> (f32, f32) {
+        let hex = hex.into();
+        let (height, width) = self.grid.cell_size;
+        (
+            hex.r as f32 * height * 0.75,
+            (hex.q as f32 + hex.r as f32 / 2.) * width,
+        )
+    }
+
+    /// The hex under the pixel, in the order (row, col) of the cell size
+    pub fn pixel_to_hex(&self, pixel: (f32, f32)) -> Axial {
+        let (height, width) = self.grid.cell_size;
+        let r = pixel.0 / (height * 0.75);
+        let q = pixel.1 / width - r / 2.;
+        Axial::from(Cube::round(q, r, -q - r))
+    }
+
+    /// Returns the center in pixels of each hex, in the order of the cells of the grid
+    pub fn enumerate_to_cell_size(&self) -> Vec<(f32, f32)> {
+        self.grid
+            .positions()
+            .map(|pos| self.hex_to_pixel(pos))
+            .collect()
+    }
+
+    // Pairs each hex with its value, skipping the ones out of the grid
+    fn cells_at(
+        &self,
+        hexes: impl IntoIterator<Item = Axial>,
+    ) -> impl Iterator<Item = (Axial, &T)> {
+        hexes
+            .into_iter()
+            .filter_map(move |hex| self.get(hex).ok().map(|value| (hex, value)))
+    }
+}
@@ -0,0 +1,196 @@
+#![allow(warnings, unused)]
+#[macro_use]
+use pretty_assertions::{assert_eq, assert_ne};
+
+use std::collections::HashSet;
+
+use crate::{Axial, Cube, GridErr, HexDirection, HexGrid, Pos};
+
+#[test]
+fn test_coordinate_conversions() {
+    for row in -4..4 {
+        for col in -4..4 {
+            let pos = Pos::new(row, col);
+            let axial = Axial::from(pos);
+            assert_eq!(axial.to_offset(), pos);
+            assert_eq!(Pos::from(axial), pos);
+            assert_eq!(Axial::from(Cube::from(axial)), axial);
+        }
+    }
+
+    // The odd rows are shoved to the east
+    assert_eq!(Axial::from(Pos::new(0, 0)), Axial::new(0, 0));
+    assert_eq!(Axial::from(Pos::new(1, 0)), Axial::new(0, 1));
+    assert_eq!(Axial::from(Pos::new(2, 0)), Axial::new(-1, 2));
+    assert_eq!(Axial::from(Pos::new(-1, 0)), Axial::new(1, -1));
+    assert_eq!(Cube::from(Axial::new(2, -3)), Cube::new(2, -3, 1));
+    assert_eq!(format!("{}", Axial::new(2, -3)), "(q: 2 r: -3)");
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_cube() {
+    Cube::new(1, 1, 1);
+}
+
+#[test]
+fn test_directions() {
+    for direction in HexDirection::ALL {
+        let hex = Axial::new(3, -2);
+        assert_eq!(hex.neighbor(direction).distance(hex), 1);
+        assert_eq!(direction.opposite().opposite(), direction);
+        assert_eq!(direction.rotate_cw().rotate_ccw(), direction);
+        assert_eq!(
+            direction.offset() + direction.opposite().offset(),
+            Axial::new(0, 0)
+        );
+    }
+    assert_eq!(HexDirection::East.rotate_ccw(), HexDirection::NorthEast);
+    assert_eq!(HexDirection::East.rotate_cw(), HexDirection::SouthEast);
+
+    // On an odd row the south east neighbor keeps the col
+    // and on an even row it is on the col before
+    let odd = Axial::from(Pos::new(1, 2));
+    assert_eq!(
+        odd.neighbor(HexDirection::SouthEast).to_offset(),
+        Pos::new(2, 3)
+    );
+    assert_eq!(
+        odd.neighbor(HexDirection::SouthWest).to_offset(),
+        Pos::new(2, 2)
+    );
+    let even = Axial::from(Pos::new(2, 2));
+    assert_eq!(
+        even.neighbor(HexDirection::SouthEast).to_offset(),
+        Pos::new(3, 2)
+    );
+    assert_eq!(
+        even.neighbor(HexDirection::NorthWest).to_offset(),
+        Pos::new(1, 1)
+    );
+}
+
+#[test]
+fn test_distance_rings_and_spirals() {
+    let center = Axial::new(1, 1);
+    assert_eq!(center.distance(Axial::new(1, 1)), 0);
+    assert_eq!(center.distance(Axial::new(4, -2)), 3);
+    assert_eq!(center.distance(Axial::new(-1, 1)), 2);
+
+    assert_eq!(center.ring(0), vec![center]);
+    for radius in 1..4 {
+        let ring = center.ring(radius);
+        assert_eq!(ring.len(), 6 * radius as usize);
+        assert!(ring.iter().all(|hex| hex.distance(center) == radius));
+        assert_eq!(ring.iter().collect::<HashSet<_>>().len(), ring.len());
+    }
+
+    let spiral = center.spiral(3);
+    assert_eq!(spiral.len(), 37);
+    assert_eq!(spiral.iter().collect::<HashSet<_>>().len(), 37);
+    assert_eq!(&spiral[1..7], &center.ring(1)[..]);
+    assert_eq!(center.spiral(-1), vec![center]);
+}
+
+#[test]
+fn test_hex_grid_storage() {
+    let mut map = HexGrid::new((3, 4), (1., 1.), 0);
+    assert_eq!(map.set(Axial::new(1, 1), 7), Ok(()));
+    assert_eq!(map.as_grid().get((1, 1)), Ok(&7));
+    assert_eq!(map.get(Cube::new(1, 1, -2)), Ok(&7));
+    *map.get_mut(Pos::new(1, 1)).unwrap() += 1;
+    assert_eq!(map.get(Pos::new(1, 1)), Ok(&8));
+
+    assert_eq!(
+        map.get(Axial::new(-1, 0)),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(0, -1)
+        })
+    );
+
+    assert_eq!(map.mov_to(Pos::new(1, 1), HexDirection::NorthEast), Ok(0));
+    assert_eq!(map.get(Pos::new(0, 2)), Ok(&8));
+    assert_eq!(
+        map.mov_to(Pos::new(0, 2), HexDirection::NorthWest),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(-1, 1)
+        })
+    );
+    assert_eq!(map.into_grid().get_flatten_grid().iter().sum::<i32>(), 8);
+}
+
+#[test]
+fn test_hex_grid_neighbors() {
+    let map = HexGrid::new((4, 4), (1., 1.), 0);
+    let around = |pos: Pos| {
+        let mut cells = map
+            .neighbors(pos)
+            .map(|(hex, _)| hex.to_offset())
+            .collect::<Vec<_>>();
+        cells.sort();
+        cells
+    };
+
+    assert_eq!(
+        around(Pos::new(1, 1)),
+        vec![
+            Pos::new(0, 1),
+            Pos::new(0, 2),
+            Pos::new(1, 0),
+            Pos::new(1, 2),
+            Pos::new(2, 1),
+            Pos::new(2, 2),
+        ]
+    );
+    assert_eq!(
+        around(Pos::new(2, 1)),
+        vec![
+            Pos::new(1, 0),
+            Pos::new(1, 1),
+            Pos::new(2, 0),
+            Pos::new(2, 2),
+            Pos::new(3, 0),
+            Pos::new(3, 1),
+        ]
+    );
+    assert_eq!(around(Pos::new(3, 3)), vec![Pos::new(2, 3), Pos::new(3, 2)]);
+
+    assert_eq!(map.ring(Pos::new(1, 1), 1).count(), 6);
+    // The rings and spirals skip the same hexes as a search over the whole grid
+    let center = Axial::from(Pos::new(1, 1));
+    let within = |radius: i32| {
+        map.as_grid()
+            .positions()
+            .filter(|pos| Axial::from(*pos).distance(center) <= radius)
+            .count()
+    };
+    assert_eq!(map.ring(center, 2).count(), within(2) - within(1));
+    assert_eq!(map.spiral(center, 2).count(), within(2));
+    assert_eq!(map.spiral(center, 9).count(), 16);
+    assert_eq!(map.distance(Pos::new(0, 0), Pos::new(3, 3)), 5);
+}
+
+#[test]
+fn test_pixel_mapping() {
+    let map = HexGrid::new((4, 5), (40., 30.), 0);
+    assert_eq!(map.hex_to_pixel(Pos::new(0, 0)), (0., 0.));
+    assert_eq!(map.hex_to_pixel(Pos::new(0, 2)), (0., 60.));
+    assert_eq!(map.hex_to_pixel(Pos::new(1, 2)), (30., 75.));
+    assert_eq!(map.hex_to_pixel(Pos::new(2, 2)), (60., 60.));
+
+    let centers = map.enumerate_to_cell_size();
+    assert_eq!(centers.len(), 20);
+    for (pos, center) in map.as_grid().positions().zip(centers) {
+        assert_eq!(map.hex_to_pixel(pos), center);
+        assert_eq!(map.pixel_to_hex(center).to_offset(), pos);
+        // Anywhere near the center is still the same hex
+        assert_eq!(
+            map.pixel_to_hex((center.0 + 9., center.1 - 9.)).to_offset(),
+            pos
+        );
+    }
+
+    // Between the rows the hexes are split by the slanted edges
+    assert_eq!(map.pixel_to_hex((15., 1.)).to_offset(), Pos::new(0, 0));
+    assert_eq!(map.pixel_to_hex((15., 14.)).to_offset(), Pos::new(1, 0));
+}
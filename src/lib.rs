@@ -26,8 +26,8 @@ Das Grid offers:
 // Creates a 10x10 grid with 0 as default value for each cell
 let mut g = das_grid::Grid::new((10, 10), (1., 1.), 0);
 
-// Set the the value 1 at position x: 5 and y: 5
-g.set((5, 5), &1);
+// Set the the value 1 at position row: 5 and col: 5
+g.set((5, 5), 1);
 ```
 
 ### Bring your own type
@@ -61,21 +61,24 @@ impl std::fmt::Display for Pawn {
 let mut g: das_grid::Grid<Pawn> = das_grid::Grid::new((10, 10), (1., 1.), Pawn::None);
 
 // Set the Player on position 5,5
-g.set((5, 5), &Pawn::Player);
+g.set((5, 5), Pawn::Player);
 
 // Move the player to right
-if let Ok(()) = g.mov_to((5, 5), das_grid::MoveDirection::Right) {
-    // "The pawn on 6,5 is Player"
-    println!("The pawn on 6,5 is {}", g.get((6, 5)).unwrap());
+if let Ok(Pawn::None) = g.mov_to((5, 5), das_grid::MoveDirection::Right) {
+    // "The pawn on 5,6 is Player"
+    println!("The pawn on 5,6 is {}", g.get((5, 6)).unwrap());
 }
 
 ```
 
-> The `mov_to` function can returns `Result<(), Err>` if the attept of move is out of the bounds of the grid
+> The `mov_to` function returns what was on the destiny, or an error if the attept of move is out of the bounds of the grid
 
 ### Moving cells
 
-Each tile of the grid is called cell and each cell is the type that you want, because it is a 2D structure each cell has an address which consists of X and Y
+Each tile of the grid is called cell and each cell is the type that you want, because it is a 2D structure each cell has an address which consists of a row and a col
+
+The address is a `das_grid::Pos` and follows the same order of the grid size `(rows, cols)`,
+a 16x9 grid has the addresses from `(0, 0)` to `(15, 8)`, any tuple `(row, col)` can be used as an address
 
 ```rust
 // Creates a 5x5 grid with 0 as default value for each cell
@@ -85,11 +88,11 @@ let mut g = das_grid::Grid::new((5, 5), (1., 1.), 0);
 println!("{:?}", g);
 // outputs:
 // Grid { rows: 5, cols: 5, cells: [
-//  0 (x: 0 y: 0) 0 (x: 1 y: 0) 0 (x: 2 y: 0) 0 (x: 3 y: 0) 0 (x: 4 y: 0)
-//  0 (x: 0 y: 1) 0 (x: 1 y: 1) 0 (x: 2 y: 1) 0 (x: 3 y: 1) 0 (x: 4 y: 1)
-//  0 (x: 0 y: 2) 0 (x: 1 y: 2) 0 (x: 2 y: 2) 0 (x: 3 y: 2) 0 (x: 4 y: 2)
-//  0 (x: 0 y: 3) 0 (x: 1 y: 3) 0 (x: 2 y: 3) 0 (x: 3 y: 3) 0 (x: 4 y: 3)
-//  0 (x: 0 y: 4) 0 (x: 1 y: 4) 0 (x: 2 y: 4) 0 (x: 3 y: 4) 0 (x: 4 y: 4)
+//  0 (row: 0 col: 0) 0 (row: 0 col: 1) 0 (row: 0 col: 2) 0 (row: 0 col: 3) 0 (row: 0 col: 4)
+//  0 (row: 1 col: 0) 0 (row: 1 col: 1) 0 (row: 1 col: 2) 0 (row: 1 col: 3) 0 (row: 1 col: 4)
+//  0 (row: 2 col: 0) 0 (row: 2 col: 1) 0 (row: 2 col: 2) 0 (row: 2 col: 3) 0 (row: 2 col: 4)
+//  0 (row: 3 col: 0) 0 (row: 3 col: 1) 0 (row: 3 col: 2) 0 (row: 3 col: 3) 0 (row: 3 col: 4)
+//  0 (row: 4 col: 0) 0 (row: 4 col: 1) 0 (row: 4 col: 2) 0 (row: 4 col: 3) 0 (row: 4 col: 4)
 // ] }
 ```
 
@@ -121,66 +124,167 @@ SOFTWARE.
 */
 
 use std::{
+    error::Error,
     fmt::{self, Display},
     ops::{Index, IndexMut},
+    sync::Arc,
 };
 
-use parse_display_derive::Display;
 use thiserror::*;
 
+mod automaton;
+mod chunked;
+mod direction;
+mod gravity;
+mod hex;
+mod moves;
+mod neighbors;
+mod pathfinding;
+mod pattern;
+mod pos;
+mod region;
+mod rules;
+mod sight;
+mod topology;
+mod traits;
+mod transform;
+mod view;
+pub use automaton::{Automaton, Edges, Neighbors};
+pub use chunked::{ChunkedGrid, ChunkedView, ChunkedViewMut};
+pub use direction::{
+    MoveDirection, MOVE_DOWN, MOVE_DOWN_LEFT, MOVE_DOWN_RIGHT, MOVE_LEFT, MOVE_RIGHT, MOVE_UP,
+    MOVE_UP_LEFT, MOVE_UP_RIGHT,
+};
+pub use hex::{Axial, Cube, HexDirection, HexGrid};
+pub use neighbors::DistanceMetric;
+pub use pathfinding::{Connectivity, MovementRange, Path};
+pub use pattern::{PatternMatch, Run};
+pub use pos::{Offset, Pos};
+pub use region::Region;
+pub use rules::{Rule, RuleContext, RuleMode, RuleSet};
+pub use sight::{Line, Supercover};
+pub use topology::Topology;
+pub use traits::{GridRead, GridWrite};
+pub use transform::Transform;
+pub use view::{GridView, GridViewMut, ViewIterMut};
+
 /// Err represents the errors that can happen on the Das Grid module
 ///
 /// GridErr::OutOfGrid when the attempt of move or set a value
-/// is beyond the bounds of grid
+/// is beyond the bounds of grid, with the position that is out
+///
+/// GridErr::RuleFailed when some rule failed to applied, with the reason given by the rule
+///
+/// GridErr::SubgridOverflow when the subgrid is greater than the parent grid, with both sizes
+///
+/// GridErr::InvalidSize when the rows or cols of a new grid aren't greater than 0
 ///
-/// GridErr::RuleFailed when some rule failed to applied
+/// GridErr::SizeMismatch when the cells given to a new grid aren't the rows x cols
 ///
-/// GridErr::SubgridOverflow when the subgrid 0x0 is greater than the parent grid
+/// GridErr::Collision when the cells of a subgrid collide with the grid,
+/// or when many moves share a source or a destiny
+///
+/// GridErr::InvalidDirection when the direction isn't allowed, like a diagonal for the gravity
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
 pub enum GridErr {
-    #[error("value is out of the grid rows and cols")]
-    OutOfGrid,
-    #[error("failed to meet the rule requirements")]
-    RuleFailed,
-    #[error("the subgrid cols or rows is greater than the parent grid")]
-    SubgridOverflow,
+    #[error("the position {pos} is out of the grid rows and cols")]
+    OutOfGrid { pos: Pos },
+    #[error("failed to meet the rule requirements{}", .0.as_ref().map(|r| format!(": {}", r)).unwrap_or_default())]
+    RuleFailed(Option<RuleReason>),
+    #[error("the subgrid {}x{} is greater than the parent grid {}x{}", .sub.0, .sub.1, .parent.0, .parent.1)]
+    SubgridOverflow { sub: (i32, i32), parent: (i32, i32) },
+    #[error("the grid size {rows}x{cols} is invalid, rows and cols must be greater than 0")]
+    InvalidSize { rows: i32, cols: i32 },
+    #[error("the grid expects {expected} cells but got {actual}")]
+    SizeMismatch { expected: usize, actual: usize },
+    #[error("the cells collide on {} positions", .positions.len())]
+    Collision { positions: Vec<Pos> },
+    #[error("the direction {direction} isn't allowed")]
+    InvalidDirection { direction: MoveDirection },
+}
+
+impl GridErr {
+    /// Creates the error of a rule failure with the reason, any error type or message
+    ///
+    /// ```.rust
+    /// use das_grid::GridErr;
+    ///
+    /// let err = GridErr::rule_failed("tile occupied");
+    /// assert_eq!(err.to_string(), "failed to meet the rule requirements: tile occupied");
+    /// ```
+    pub fn rule_failed(reason: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        GridErr::RuleFailed(Some(RuleReason(Arc::from(reason.into()))))
+    }
+
+    /// The reason of a rule failure if it is of the given error type
+    ///
+    /// ```.rust
+    /// use das_grid::GridErr;
+    ///
+    /// #[derive(Debug)]
+    /// struct NotYourTurn;
+    ///
+    /// impl std::fmt::Display for NotYourTurn {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    ///         write!(f, "not your turn")
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for NotYourTurn {}
+    ///
+    /// let err = GridErr::rule_failed(NotYourTurn);
+    /// assert!(err.reason::<NotYourTurn>().is_some());
+    /// assert!(GridErr::RuleFailed(None).reason::<NotYourTurn>().is_none());
+    /// ```
+    pub fn reason<E: Error + 'static>(&self) -> Option<&E> {
+        match self {
+            GridErr::RuleFailed(Some(reason)) => reason.0.downcast_ref::<E>(),
+            _ => None,
+        }
+    }
 }
 
-/// Represents the possible direction to move
+/// The reason given by a rule when it fails, it can hold any error type
 ///
-/// MoveDirection::Right
-/// MoveDirection::Left
-/// MoveDirection::Up
-/// MoveDirection::Down
-#[derive(Debug, PartialEq, Eq, Display)]
-pub enum MoveDirection {
-    #[display("Right (0, 1)")]
-    Right,
-    #[display("Left (0, -1)")]
-    Left,
-    #[display("Up (-1, 0")]
-    Up,
-    #[display("Down (1, 0)")]
-    Down,
+/// Two reasons are equal when they show the same message
+#[derive(Clone)]
+pub struct RuleReason(Arc<dyn Error + Send + Sync>);
+
+impl RuleReason {
+    /// The error given by the rule
+    pub fn error(&self) -> &(dyn Error + Send + Sync + 'static) {
+        &*self.0
+    }
 }
 
-/// Represent move to right position on Das Grid (0, 1)
-pub const MOVE_RIGHT: (i32, i32) = (0, 1);
+impl fmt::Debug for RuleReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
 
-/// Represent move to left position on Das Grid (0, -1)
-pub const MOVE_LEFT: (i32, i32) = (0, -1);
+impl fmt::Display for RuleReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
 
-/// Represent move to up position on Das Grid (-1, 0)
-pub const MOVE_UP: (i32, i32) = (-1, 0);
+impl PartialEq for RuleReason {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || self.0.to_string() == other.0.to_string()
+    }
+}
 
-/// Represent move to down position on Das Grid (1, 0)
-pub const MOVE_DOWN: (i32, i32) = (1, 0);
+impl Eq for RuleReason {}
 
 /// Stores the grid values and the cells
 /// The grid itself representation is a flatten vector which is transformed
 /// for 2D representation when called by the user
 ///
-/// The cells are internally manage by a `Vec<T>`
+/// The cells are internally manage by a `Vec<T>` stored row by row (row-major),
+/// the position `Pos { row, col }` lives at `row * cols + col`
+///
+/// Every function which receives a position accepts a `Pos` or a tuple `(row, col)`
 ///
 /// So to create a grid with 4x4 (collums and rows)
 ///
@@ -197,81 +301,195 @@ pub const MOVE_DOWN: (i32, i32) = (1, 0);
 /// // And it will have 200 cells!
 /// assert_eq!(grid.size(), 200);
 /// ```
-
-pub struct Grid<T: Copy + Clone> {
+pub struct Grid<T> {
     pub(crate) rows: i32,
     pub(crate) cols: i32,
     pub(crate) cell_size: (f32, f32),
     pub(crate) initial_value: T,
+    pub(crate) topology: Topology,
     pub(crate) cells: Vec<T>,
 }
 
-impl<T: Copy + Clone> Grid<T> {
+impl<T> Grid<T> {
     /// Creates a grid of size rows x columns with default value passed on the third parameter
     /// For example this will generate a 2x2 grid of value 1:
     /// ```.rust
     /// let grid = das_grid::Grid::new((2, 2),(1., 1.), 1);
     /// assert_eq!(grid.size(), 4);
     /// ```
+    ///
+    /// It panics if rows or cols aren't greater than 0, see `Grid::try_new` for
+    /// the version which returns the error instead
     pub fn new(frame_size: (i32, i32), cell_size: (f32, f32), value: T) -> Self
     where
-        T: Clone + Copy,
+        T: Clone,
     {
-        let (rows, cols) = frame_size;
+        Self::try_new(frame_size, cell_size, value).unwrap_or_else(|err| panic!("{}", err))
+    }
 
-        if (rows * cols) == 0 {
-            panic!("0x0 grid is forbidden")
-        }
+    /// Creates a grid of size rows x columns with default value passed on the third parameter
+    ///
+    /// If the rows or cols aren't greater than 0 it returns the error GridErr::InvalidSize
+    ///
+    /// ```.rust
+    /// let grid = das_grid::Grid::try_new((2, 3), (1., 1.), 1).unwrap();
+    /// assert_eq!(grid.size(), 6);
+    ///
+    /// let err = das_grid::Grid::try_new((0, 3), (1., 1.), 1).err().unwrap();
+    /// assert_eq!(err, das_grid::GridErr::InvalidSize { rows: 0, cols: 3 });
+    /// ```
+    pub fn try_new(frame_size: (i32, i32), cell_size: (f32, f32), value: T) -> Result<Self, GridErr>
+    where
+        T: Clone,
+    {
+        let (rows, cols) = frame_size;
+        let size = Self::check_grid_size(frame_size)?;
 
+        let cells = vec![value.clone(); size];
         let initial_value = value;
-        let cells = vec![value; (rows * cols) as usize];
 
-        Self {
+        Ok(Self {
             rows,
             cols,
             initial_value,
+            topology: Topology::default(),
             cells,
             cell_size,
-        }
+        })
     }
 
-    /// Creates a grid from a given vector with quadratic size
+    /// Creates a grid of size rows x columns calling the given function for each cell,
+    /// which is useful for values that can't be cloned like `Box<dyn Trait>`
+    ///
+    /// The function is called once more to produce the empty value of the grid
+    ///
+    /// ```.rust
+    /// let grid = das_grid::Grid::new_with((2, 2), (1., 1.), || Box::new(1));
+    /// assert_eq!(grid.get((1, 1)), Ok(&Box::new(1)));
+    /// ```
+    ///
+    /// It panics if rows or cols aren't greater than 0, see `Grid::try_new_with` for
+    /// the version which returns the error instead
+    pub fn new_with<F>(frame_size: (i32, i32), cell_size: (f32, f32), f: F) -> Self
+    where
+        F: FnMut() -> T,
+    {
+        Self::try_new_with(frame_size, cell_size, f).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a grid of size rows x columns calling the given function for each cell
+    ///
+    /// If the rows or cols aren't greater than 0 it returns the error GridErr::InvalidSize
+    ///
+    /// ```.rust
+    /// let grid = das_grid::Grid::try_new_with((2, 3), (1., 1.), String::new).unwrap();
+    /// assert_eq!(grid.size(), 6);
+    /// ```
+    pub fn try_new_with<F>(
+        frame_size: (i32, i32),
+        cell_size: (f32, f32),
+        mut f: F,
+    ) -> Result<Self, GridErr>
+    where
+        F: FnMut() -> T,
+    {
+        let (rows, cols) = frame_size;
+        let size = Self::check_grid_size(frame_size)?;
+
+        let initial_value = f();
+        let cells = (0..size).map(|_| f()).collect();
+
+        Ok(Self {
+            rows,
+            cols,
+            initial_value,
+            topology: Topology::default(),
+            cells,
+            cell_size,
+        })
+    }
+
+    /// Creates a grid from a given vector with the size rows x columns
     /// For example this will generate a 2x2 grid
     /// ```.rust
     /// let mut grid = das_grid::Grid::new_from_vector((2, 2), (1.,1.), vec![1, 2, 3, 4]);
     /// assert_eq!(grid.size(), 4);
     /// ```
-    pub fn new_from_vector(frame_size: (i32, i32), cell_size: (f32, f32), vec: Vec<T>) -> Self {
-        let (rows, cols) = frame_size;
-
-        if vec.len() % 2 != 0 {
-            panic!("The vector isn't multiple of 2");
-        }
+    ///
+    /// The first value of the vector is the empty value of the grid,
+    /// use `Grid::set_empty_value` to choose another one
+    ///
+    /// It panics if the size is invalid or doesn't match the vector length,
+    /// see `Grid::try_from_vec` for the version which returns the error instead
+    pub fn new_from_vector(frame_size: (i32, i32), cell_size: (f32, f32), vec: Vec<T>) -> Self
+    where
+        T: Clone,
+    {
+        Self::try_from_vec(frame_size, cell_size, vec).unwrap_or_else(|err| panic!("{}", err))
+    }
 
-        if vec.len() == 0 {
-            panic!("0x0 grid is forbidden")
-        }
+    /// Creates a grid from a given vector with the size rows x columns
+    ///
+    /// If the rows or cols aren't greater than 0 it returns the error GridErr::InvalidSize
+    ///
+    /// And if the vector length isn't rows x cols it returns the error GridErr::SizeMismatch
+    ///
+    /// ```.rust
+    /// let grid = das_grid::Grid::try_from_vec((3, 3), (1., 1.), (1..=9).collect()).unwrap();
+    /// assert_eq!(grid.get((2, 2)), Ok(&9));
+    ///
+    /// let err = das_grid::Grid::try_from_vec((3, 3), (1., 1.), vec![1, 2, 3]).err().unwrap();
+    /// assert_eq!(err, das_grid::GridErr::SizeMismatch { expected: 9, actual: 3 });
+    /// ```
+    pub fn try_from_vec(
+        frame_size: (i32, i32),
+        cell_size: (f32, f32),
+        vec: Vec<T>,
+    ) -> Result<Self, GridErr>
+    where
+        T: Clone,
+    {
+        let (rows, cols) = frame_size;
+        let size = Self::check_grid_size(frame_size)?;
 
-        if rows * cols != vec.len() as i32 {
-            panic!("cols and rows should be same vector size")
+        if size != vec.len() {
+            return Err(GridErr::SizeMismatch {
+                expected: size,
+                actual: vec.len(),
+            });
         }
 
-        let initial_value = vec.first().unwrap().clone();
-        let cells = vec.to_vec();
+        let initial_value = vec[0].clone();
+        let cells = vec;
 
-        Self {
+        Ok(Self {
             rows,
             cols,
             initial_value,
+            topology: Topology::default(),
             cells,
             cell_size,
+        })
+    }
+
+    // Check if rows and cols are positive and return the amount of cells
+    fn check_grid_size(frame_size: (i32, i32)) -> Result<usize, GridErr> {
+        let (rows, cols) = frame_size;
+
+        if rows <= 0 || cols <= 0 {
+            return Err(GridErr::InvalidSize { rows, cols });
+        }
+
+        match rows.checked_mul(cols) {
+            Some(size) => Ok(size as usize),
+            None => Err(GridErr::InvalidSize { rows, cols }),
         }
     }
 
     /// Stamps the subgrid into the destiny grid, merging both
     ///
     /// If the sub grid is greater than the main grid it return an error of GridErr::SubgridOverflow
-    /// Or if the dest x, y grid is out of bounds it return error GridErr::OutOfGrid
+    /// Or if the dest position is out of bounds it return error GridErr::OutOfGrid
     ///
     /// ```.rust
     /// let mut grid: das_grid::Grid<i32> = das_grid::Grid::new((10, 10), (1., 1.), 0);
@@ -282,49 +500,109 @@ impl<T: Copy + Clone> Grid<T> {
     /// assert_eq!(grid.get((6, 5)).unwrap(), &1);
     /// assert_eq!(grid.get((6, 6)).unwrap(), &1);
     /// ```
-    pub fn stamp_subgrid(&mut self, dst: (i32, i32), sub_grid: Grid<T>) -> Result<(), GridErr> {
+    pub fn stamp_subgrid(&mut self, dst: impl Into<Pos>, sub_grid: Grid<T>) -> Result<(), GridErr> {
+        let dst = dst.into();
         self.check_grid_overflow(&sub_grid)?;
         self.check_grid_bounds(dst)?;
 
-        for sub_index in sub_grid.enumerate() {
-            if let Ok(subv) = sub_grid.get(sub_index) {
-                // Sum origin of subgrid and dest cells
-                let dest = (dst.0 + sub_index.0, dst.1 + sub_index.1);
+        for (sub_index, subv) in sub_grid.positions().zip(sub_grid.cells) {
+            // Sum origin of subgrid and dest cells
+            let dest = dst + Offset::from(sub_index);
 
-                // Ok if the subgrid bleeds
-                match self.set(dest, &subv) {
-                    Ok(_) => (),
-                    _ => (),
-                }
-            }
+            // Ok if the subgrid bleeds
+            let _ = self.set(dest, subv);
         }
 
         Ok(())
     }
 
+    /// Stamps the subgrid into the destiny grid after applying the transform,
+    /// skipping the transparent cells of the subgrid
+    ///
+    /// The transparent function receives the position of the cell on the subgrid
+    /// (before the transform) and its value, so it works as a mask or as a predicate
+    ///
+    /// Returns the positions of the destiny grid that were written, wrapped with the topology
+    /// of the grid, the cells that bleed out of the grid aren't written
+    ///
+    /// If the transformed sub grid is greater than the main grid it return an error of GridErr::SubgridOverflow
+    /// Or if the dest position is out of bounds it return error GridErr::OutOfGrid
+    ///
+    /// ```.rust
+    /// use das_grid::{Grid, Pos, Transform};
+    ///
+    /// let mut grid = Grid::new((4, 4), (1., 1.), 9);
+    /// // The L piece, 0 is empty
+    /// let piece = Grid::new_from_vector((3, 2), (1., 1.), vec![
+    ///     1, 0,
+    ///     1, 0,
+    ///     1, 1,
+    /// ]);
+    /// let touched = grid
+    ///     .stamp_subgrid_transformed((0, 0), piece, Transform::Rotate90Cw, |_, v| *v == 0)
+    ///     .unwrap();
+    /// assert_eq!(touched, vec![Pos::new(0, 2), Pos::new(0, 1), Pos::new(0, 0), Pos::new(1, 0)]);
+    /// assert_eq!(grid.get_row(0).unwrap(), vec![1, 1, 1, 9]);
+    /// assert_eq!(grid.get_row(1).unwrap(), vec![1, 9, 9, 9]);
+    /// ```
+    pub fn stamp_subgrid_transformed<F>(
+        &mut self,
+        dst: impl Into<Pos>,
+        sub_grid: Grid<T>,
+        transform: Transform,
+        transparent: F,
+    ) -> Result<Vec<Pos>, GridErr>
+    where
+        F: Fn(Pos, &T) -> bool,
+    {
+        let dst = dst.into();
+        let frame_size = (sub_grid.rows, sub_grid.cols);
+        self.check_frame_overflow(transform.frame_size(frame_size))?;
+        self.check_grid_bounds(dst)?;
+
+        let mut touched = vec![];
+        for (sub_index, subv) in sub_grid.positions().zip(sub_grid.cells) {
+            if transparent(sub_index, &subv) {
+                continue;
+            }
+
+            // The position actually written, wrapped with the topology of the grid
+            let dest = dst + Offset::from(transform.apply(sub_index, frame_size));
+            if let Ok(dest) = self.wrap(dest) {
+                self.set(dest, subv)?;
+                touched.push(dest);
+            }
+        }
+
+        Ok(touched)
+    }
+
     /// Creates the a new grid which is a snapshot of the main grid on the given position and size
     ///
+    /// The cells are copied, see `Grid::view` and `Grid::view_mut` to borrow them instead
+    ///
     /// If the sub grid is greater than the main grid it return an error of GridErr::SubgridOverflow
     ///
-    /// Or if the dest x, y grid is out of bounds it return error GridErr::OutOfGrid
+    /// Or if the dest position is out of bounds it return error GridErr::OutOfGrid
     ///
     /// ```.rust
     /// let mut grid = das_grid::Grid::new_from_vector((4, 4), (1., 1.), (1..=16).collect());
     /// let sub_grid = grid.get_subgrid((2, 2), 2, 2).unwrap();
     /// assert_eq!(sub_grid.get_flatten_grid(), vec![11, 12, 15, 16]);
     /// ```
-    pub fn get_subgrid(&self, src: (i32, i32), rows: i32, cols: i32) -> Result<Grid<T>, GridErr> {
+    pub fn get_subgrid(&self, src: impl Into<Pos>, rows: i32, cols: i32) -> Result<Grid<T>, GridErr>
+    where
+        T: Clone,
+    {
+        let src = src.into();
         self.check_grid_bounds(src)?;
-        let mut sub_grid = Grid::new((rows, cols), self.cell_size, self.initial_value);
+        let mut sub_grid = Grid::try_new((rows, cols), self.cell_size, self.initial_value.clone())?;
         self.check_grid_overflow(&sub_grid)?;
 
-        for sub_index in sub_grid.enumerate() {
-            let dest = (src.0 + sub_index.0, src.1 + sub_index.1);
+        for sub_index in sub_grid.positions() {
+            let dest = src + Offset::from(sub_index);
             if let Ok(subv) = self.get(dest) {
-                match sub_grid.set(sub_index, &subv) {
-                    Ok(_) => (),
-                    _ => (),
-                }
+                let _ = sub_grid.set(sub_index, subv.clone());
             }
         }
 
@@ -332,11 +610,11 @@ impl<T: Copy + Clone> Grid<T> {
     }
 
     /// Stamps the subgrid into the destiny grid, merging both
-    /// Only if no rule return error
+    /// Only if no rule return error, otherwise the grid isn't changed
     ///
     /// If the sub grid is greater than the main grid it return an error of GridErr::SubgridOverflow
     ///
-    /// Or if the dest x, y grid is out of bounds it return error GridErr::OutOfGrid
+    /// Or if the dest position is out of bounds it return error GridErr::OutOfGrid
     ///
     /// And if a rule some rule failed it will return GridErr::RuleFailed
     ///
@@ -346,7 +624,7 @@ impl<T: Copy + Clone> Grid<T> {
     ///
     /// let rule_not_1 = |_: (i32, i32), value: &i32| -> Result<(), das_grid::GridErr> {
     ///     if *value == 1 {
-    ///         return Err(das_grid::GridErr::RuleFailed);
+    ///         return Err(das_grid::GridErr::RuleFailed(None));
     ///     }
     ///     Ok(())
     /// };
@@ -357,130 +635,280 @@ impl<T: Copy + Clone> Grid<T> {
     /// ```
     pub fn stamp_subgrid_with_rules<R>(
         &mut self,
-        dst: (i32, i32),
+        dst: impl Into<Pos>,
         sub_grid: Grid<T>,
         rules: Vec<R>,
     ) -> Result<(), GridErr>
     where
         R: Fn((i32, i32), &T) -> Result<(), GridErr>,
     {
+        let dst = dst.into();
         self.check_grid_overflow(&sub_grid)?;
         self.check_grid_bounds(dst)?;
 
-        for sub_index in sub_grid.enumerate() {
-            if let Ok(subv) = sub_grid.get(sub_index) {
-                // Sum origin of subgrid and dest cells
-                let dest = (dst.0 + sub_index.0, dst.1 + sub_index.1);
-
-                // Get the destiny
-                let destv = self.get(dest)?;
+        // Test rules on every dest pos and value before changing any of them
+        for sub_index in sub_grid.positions() {
+            // Sum origin of subgrid and dest cells
+            let dest = dst + Offset::from(sub_index);
 
-                // Test rules on dest pos and value
-                for rule in rules.iter() {
-                    rule(dest, destv)?;
-                }
+            // Get the destiny
+            let destv = self.get(dest)?;
 
-                // Ok if the subgrid bleeds
-                match self.set(dest, &subv) {
-                    Ok(_) => (),
-                    _ => (),
-                }
+            for rule in rules.iter() {
+                rule(dest.into(), destv)?;
             }
         }
 
+        for (sub_index, subv) in sub_grid.positions().zip(sub_grid.cells) {
+            let _ = self.set(dst + Offset::from(sub_index), subv);
+        }
+
+        Ok(())
+    }
+
+    /// Checks where the subgrid would collide if stamped on the destiny position,
+    /// without changing the grid
+    ///
+    /// The collides function receives the value of the destiny cell (None when it is
+    /// out of the grid) and the value of the subgrid cell
+    ///
+    /// Returns the destiny positions that collide, empty if the subgrid fits
+    ///
+    /// ```.rust
+    /// use das_grid::{Grid, Pos};
+    ///
+    /// let mut grid = Grid::new((3, 3), (1., 1.), 0);
+    /// grid.set((1, 1), 7);
+    /// let piece = Grid::new((2, 2), (1., 1.), 1);
+    /// let collides = |cur: Option<&i32>, _: &i32| cur != Some(&0);
+    ///
+    /// assert_eq!(grid.can_stamp((0, 0), &piece, collides), vec![Pos::new(1, 1)]);
+    /// assert_eq!(grid.can_stamp((1, 2), &piece, collides).len(), 2);
+    /// assert!(grid.can_stamp((0, 0), &Grid::new((1, 1), (1., 1.), 1), collides).is_empty());
+    /// ```
+    pub fn can_stamp<F>(&self, dst: impl Into<Pos>, sub_grid: &Grid<T>, collides: F) -> Vec<Pos>
+    where
+        F: Fn(Option<&T>, &T) -> bool,
+    {
+        let dst = dst.into();
+        sub_grid
+            .positions()
+            .zip(sub_grid.cells.iter())
+            .map(|(sub_index, subv)| (dst + Offset::from(sub_index), subv))
+            .filter(|(dest, subv)| collides(self.get(*dest).ok(), subv))
+            .map(|(dest, _)| dest)
+            .collect()
+    }
+
+    /// Stamps the subgrid into the destiny grid only if no cell collides,
+    /// so either every cell is stamped or none
+    ///
+    /// The collides function works like on `can_stamp`, the cells out of the grid
+    /// which don't collide are skipped
+    ///
+    /// If any cell collides it return an error of GridErr::Collision with the positions
+    ///
+    /// ```.rust
+    /// use das_grid::{Grid, GridErr, Pos};
+    ///
+    /// let mut grid = Grid::new((3, 3), (1., 1.), 0);
+    /// grid.set((2, 2), 7);
+    /// let collides = |cur: Option<&i32>, _: &i32| cur != Some(&0);
+    ///
+    /// let piece = Grid::new((2, 2), (1., 1.), 1);
+    /// assert!(grid.try_stamp((0, 0), piece, collides).is_ok());
+    ///
+    /// let piece = Grid::new((2, 2), (1., 1.), 2);
+    /// assert_eq!(
+    ///     grid.try_stamp((1, 1), piece, collides),
+    ///     Err(GridErr::Collision { positions: vec![Pos::new(1, 1), Pos::new(2, 2)] })
+    /// );
+    /// assert_eq!(grid.get((1, 2)), Ok(&0));
+    /// ```
+    pub fn try_stamp<F>(
+        &mut self,
+        dst: impl Into<Pos>,
+        sub_grid: Grid<T>,
+        collides: F,
+    ) -> Result<(), GridErr>
+    where
+        F: Fn(Option<&T>, &T) -> bool,
+    {
+        let dst = dst.into();
+        let positions = self.can_stamp(dst, &sub_grid, collides);
+        if !positions.is_empty() {
+            return Err(GridErr::Collision { positions });
+        }
+
+        for (sub_index, subv) in sub_grid.positions().zip(sub_grid.cells) {
+            let _ = self.set(dst + Offset::from(sub_index), subv);
+        }
+
         Ok(())
     }
 
     // Check if subgrid isn't bigger than the destiny grid
     fn check_grid_overflow(&self, sub_grid: &Grid<T>) -> Result<(), GridErr> {
-        if sub_grid.cols > self.cols {
-            return Err(GridErr::SubgridOverflow);
-        }
+        self.check_frame_overflow((sub_grid.rows, sub_grid.cols))
+    }
 
-        if sub_grid.rows > self.rows {
-            return Err(GridErr::SubgridOverflow);
+    // Check if a subgrid of the given size isn't bigger than the destiny grid
+    fn check_frame_overflow(&self, frame_size: (i32, i32)) -> Result<(), GridErr> {
+        if frame_size.0 > self.rows || frame_size.1 > self.cols {
+            return Err(GridErr::SubgridOverflow {
+                sub: frame_size,
+                parent: (self.rows, self.cols),
+            });
         }
 
         Ok(())
     }
 
-    /// Internally checks if the index (x, y) is inside of the bounds of the grid
-    fn check_grid_bounds(&self, dst: (i32, i32)) -> Result<(), GridErr> {
-        let (x, y) = dst;
+    // Flatten index of the position, the position must be checked by
+    // check_grid_bounds before, since the cells are stored row by row
+    fn cell_index(&self, pos: Pos) -> usize {
+        let pos = self.wrapped(pos);
+        (pos.row * self.cols + pos.col) as usize
+    }
 
-        if x < 0 || x >= self.rows {
-            return Err(GridErr::OutOfGrid);
+    // A grid of the same size and cell size holding the given cells
+    pub(crate) fn with_cells<U: Default>(&self, cells: Vec<U>) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cell_size: self.cell_size,
+            initial_value: U::default(),
+            topology: self.topology,
+            cells,
+        }
+    }
+
+    /// Internally checks if the position is inside of the bounds of the grid,
+    /// the axes wrapped by the topology have no bounds
+    fn check_grid_bounds(&self, dst: Pos) -> Result<(), GridErr> {
+        let pos = self.wrapped(dst);
+        if pos.row < 0 || pos.row >= self.rows {
+            return Err(GridErr::OutOfGrid { pos: dst });
         }
 
-        if y < 0 || y >= self.cols {
-            return Err(GridErr::OutOfGrid);
+        if pos.col < 0 || pos.col >= self.cols {
+            return Err(GridErr::OutOfGrid { pos: dst });
         }
 
         Ok(())
     }
 
-    /// Sets a given value to the position (x, y)
+    /// Sets a given value to the position (row, col)
     ///
     /// Be careful if the value is out of the bounds of grid it will return an error
     /// with the type of GridErr::OutOfGrid
     ///
     /// ```.rust
     /// let mut grid = das_grid::Grid::new((2, 2),(1., 1.), 1);
-    /// assert!(grid.set((0, 0), &1).is_ok());
+    /// assert!(grid.set((0, 0), 1).is_ok());
     /// ```
-    pub fn set(&mut self, dst: (i32, i32), value: &T) -> Result<(), GridErr>
-    where
-        T: Copy,
-    {
-        let (x, y) = dst;
+    pub fn set(&mut self, dst: impl Into<Pos>, value: T) -> Result<(), GridErr> {
+        self.replace(dst, value)?;
+        Ok(())
+    }
 
-        self.check_grid_bounds(dst)?;
+    /// Sets a given value to the position (row, col) and returns the previous value
+    ///
+    /// Be careful if the value is out of the bounds of grid it will return an error
+    /// with the type of GridErr::OutOfGrid
+    ///
+    /// ```.rust
+    /// let mut grid = das_grid::Grid::new((2, 2), (1., 1.), String::from("a"));
+    /// assert_eq!(grid.replace((0, 1), String::from("b")), Ok(String::from("a")));
+    /// assert_eq!(grid.get((0, 1)), Ok(&String::from("b")));
+    /// ```
+    pub fn replace(&mut self, dst: impl Into<Pos>, value: T) -> Result<T, GridErr> {
+        let cell = self.get_mut(dst)?;
+        Ok(std::mem::replace(cell, value))
+    }
 
-        if let Some(cell) = self.cells.get_mut((x * self.rows + y) as usize) {
-            *cell = *value;
-        }
+    /// The empty value of the grid, left behind on the cells vacated by `take`, `mov` and the others moves
+    ///
+    /// It is the value given to `Grid::new`, or the first cell of `Grid::new_from_vector`
+    ///
+    /// ```.rust
+    /// let grid = das_grid::Grid::new_from_vector((1, 2), (1., 1.), vec![5, 0]);
+    /// assert_eq!(grid.empty_value(), &5);
+    /// ```
+    pub fn empty_value(&self) -> &T {
+        &self.initial_value
+    }
 
-        Ok(())
+    /// Sets the empty value of the grid, the cells already on the grid aren't changed
+    ///
+    /// ```.rust
+    /// let mut grid = das_grid::Grid::new_from_vector((1, 3), (1., 1.), vec!['K', '.', '.']);
+    /// grid.set_empty_value('.');
+    /// assert_eq!(grid.mov((0, 0), (0, 2)), Ok('.'));
+    /// assert_eq!(grid.get_flatten_grid(), vec!['.', '.', 'K']);
+    /// ```
+    pub fn set_empty_value(&mut self, value: T) {
+        self.initial_value = value;
     }
 
-    /// Sets a given value to the position (x, y)
+    /// Takes the value out of the position (row, col) leaving the empty value of the grid in place
+    ///
+    /// Be careful if the value is out of the bounds of grid it will return an error
+    /// with the type of GridErr::OutOfGrid
+    ///
+    /// ```.rust
+    /// let mut grid = das_grid::Grid::new((2, 2), (1., 1.), vec![]);
+    /// grid.set((1, 1), vec![1, 2]);
+    /// assert_eq!(grid.take((1, 1)), Ok(vec![1, 2]));
+    /// assert_eq!(grid.get((1, 1)), Ok(&vec![]));
+    /// ```
+    pub fn take(&mut self, src: impl Into<Pos>) -> Result<T, GridErr>
+    where
+        T: Clone,
+    {
+        let empty = self.initial_value.clone();
+        self.replace(src, empty)
+    }
+
+    /// Sets a given value to the position (row, col)
     /// Only if no rule return error
     ///
     /// ```.rust
     /// let mut grid = das_grid::Grid::new((2, 2),(1., 1.), 0);
-    /// assert!(grid.set((0, 1), &1).is_ok());
+    /// assert!(grid.set((0, 1), 1).is_ok());
     ///
     /// let rule_not_1 = |_: (i32, i32), value: &i32| -> Result<(), das_grid::GridErr> {
     ///     if *value == 1 {
-    ///         return Err(das_grid::GridErr::RuleFailed);
+    ///         return Err(das_grid::GridErr::RuleFailed(None));
     ///     }
     ///     Ok(())
     /// };
     ///
     /// assert!(
-    ///     grid.set_with_rules((0, 1), &1, vec![rule_not_1])
+    ///     grid.set_with_rules((0, 1), 1, vec![rule_not_1])
     ///         .err()
     ///         .unwrap()
-    ///         == das_grid::GridErr::RuleFailed
+    ///         == das_grid::GridErr::RuleFailed(None)
     /// );
     /// ```
     pub fn set_with_rules<R>(
         &mut self,
-        dst: (i32, i32),
-        value: &T,
+        dst: impl Into<Pos>,
+        value: T,
         rules: Vec<R>,
     ) -> Result<(), GridErr>
     where
         R: Fn((i32, i32), &T) -> Result<(), GridErr>,
     {
+        let dst = dst.into();
         for rule in rules.iter() {
-            rule(dst, value)?;
+            rule(dst.into(), &value)?;
         }
         self.set(dst, value)?;
         Ok(())
     }
 
-    /// Gets a give value to the position (x, y) as mutable
+    /// Gets a give value to the position (row, col) as mutable
     ///
     /// Be careful if the value is out of the bounds of grid it will return an error
     /// with the type of GridErr::OutOfGrid
@@ -491,15 +919,15 @@ impl<T: Copy + Clone> Grid<T> {
     /// *v = 50;
     /// assert_eq!(grid.get((0, 0)).unwrap_or(&0), &50);
     /// ```
-    pub fn get_mut(&mut self, src: (i32, i32)) -> Result<&mut T, GridErr> {
-        let (x, y) = src;
-
+    pub fn get_mut(&mut self, src: impl Into<Pos>) -> Result<&mut T, GridErr> {
+        let src = src.into();
         self.check_grid_bounds(src)?;
 
-        Ok(self.cells.get_mut((x * self.rows + y) as usize).unwrap())
+        let idx = self.cell_index(src);
+        Ok(&mut self.cells[idx])
     }
 
-    /// Gets a give value to the position (x, y)
+    /// Gets a give value to the position (row, col)
     ///
     /// Be careful if the value is out of the bounds of grid it will return an error
     /// with the type of GridErr::OutOfGrid
@@ -509,34 +937,64 @@ impl<T: Copy + Clone> Grid<T> {
     /// let v = grid.get((0, 0));
     /// assert_eq!(v, Ok(&1));
     /// ```
-    pub fn get(&self, src: (i32, i32)) -> Result<&T, GridErr> {
-        let (x, y) = src;
-
+    pub fn get(&self, src: impl Into<Pos>) -> Result<&T, GridErr> {
+        let src = src.into();
         self.check_grid_bounds(src)?;
 
-        Ok(self.cells.get((x * self.rows + y) as usize).unwrap())
+        Ok(&self.cells[self.cell_index(src)])
+    }
+
+    /// Moves a given value from position (row, col) to destiny position (row, col)
+    ///
+    /// The source gets the empty value and the value that was on the destiny is returned,
+    /// like a captured piece on a chess board
+    ///
+    /// Be careful if the value is out of the bounds of grid it will return an error
+    /// with the type of GridErr::OutOfGrid
+    ///
+    /// ```.rust
+    /// let mut grid = das_grid::Grid::new((2, 2),(1., 1.), 0);
+    /// grid.set((0, 0), 1);
+    /// grid.set((1, 1), 2);
+    /// assert_eq!(grid.mov((0, 0), (1, 1)), Ok(2));
+    /// assert_eq!(grid.get_flatten_grid(), vec![0, 0, 0, 1]);
+    /// ```
+    pub fn mov(&mut self, src: impl Into<Pos>, dest: impl Into<Pos>) -> Result<T, GridErr>
+    where
+        T: Clone,
+    {
+        let empty = self.initial_value.clone();
+        self.mov_leaving(src, dest, empty)
     }
 
-    /// Moves a given value from position (x, y) to destiny position (x, y)
+    /// Moves a given value from position (row, col) to destiny position (row, col),
+    /// leaving the given value on the source instead of the empty value
+    ///
+    /// The value that was on the destiny is returned, as `Grid::mov` does
     ///
     /// Be careful if the value is out of the bounds of grid it will return an error
     /// with the type of GridErr::OutOfGrid
     ///
     /// ```.rust
-    /// let mut grid = das_grid::Grid::new((2, 2),(1., 1.), 1);
-    /// assert_eq!(grid.mov((0, 0), (1, 1)), Ok(()));
+    /// let mut grid = das_grid::Grid::new_from_vector((1, 3), (1., 1.), vec!['K', '.', 'p']);
+    /// // The king captures the pawn and leaves a mark behind
+    /// assert_eq!(grid.mov_leaving((0, 0), (0, 2), 'x'), Ok('p'));
+    /// assert_eq!(grid.get_flatten_grid(), vec!['x', '.', 'K']);
     /// ```
-    pub fn mov(&mut self, src: (i32, i32), dest: (i32, i32)) -> Result<(), GridErr> {
+    pub fn mov_leaving(
+        &mut self,
+        src: impl Into<Pos>,
+        dest: impl Into<Pos>,
+        leave: T,
+    ) -> Result<T, GridErr> {
+        let (src, dest) = (src.into(), dest.into());
         self.check_grid_bounds(src)?;
         self.check_grid_bounds(dest)?;
-        let prev = *self.get_mut(src).unwrap();
-        self.set(src, &self.initial_value.clone())?;
-        self.set(dest, &prev)?;
-
-        Ok(())
+        let prev = self.replace(src, leave)?;
+        self.replace(dest, prev)
     }
 
-    /// Moves a given value from position (x, y) to destiny position (x, y)
+    /// Moves a given value from position (row, col) to destiny position (row, col)
     /// Only if no rule return error
     ///
     /// Be careful if the value is out of the bounds of grid it will return an error
@@ -544,13 +1002,15 @@ impl<T: Copy + Clone> Grid<T> {
     ///
     /// And if a rule some rule failed it will return GridErr::RuleFailed
     ///
+    /// The value that was on the destiny is returned, as `Grid::mov` does
+    ///
     /// ```.rust
     /// let mut grid = das_grid::Grid::new((2, 2),(1., 1.), 0);
-    /// assert!(grid.set((0, 1), &1).is_ok());
+    /// assert!(grid.set((0, 1), 1).is_ok());
     ///
     /// let rule_not_1 = |_: (i32, i32), value: &i32| -> Result<(), das_grid::GridErr> {
     ///     if *value == 1 {
-    ///         return Err(das_grid::GridErr::RuleFailed);
+    ///         return Err(das_grid::GridErr::RuleFailed(None));
     ///     }
     ///     Ok(())
     /// };
@@ -559,91 +1019,81 @@ impl<T: Copy + Clone> Grid<T> {
     ///     grid.mov_with_rules((0, 0), (0, 1), vec![rule_not_1])
     ///         .err()
     ///         .unwrap()
-    ///         == das_grid::GridErr::RuleFailed
+    ///         == das_grid::GridErr::RuleFailed(None)
     /// );
     /// ```
     pub fn mov_with_rules<R>(
         &mut self,
-        src: (i32, i32),
-        dst: (i32, i32),
+        src: impl Into<Pos>,
+        dst: impl Into<Pos>,
         rules: Vec<R>,
-    ) -> Result<(), GridErr>
+    ) -> Result<T, GridErr>
     where
+        T: Clone,
         R: Fn((i32, i32), &T) -> Result<(), GridErr>,
     {
+        let (src, dst) = (src.into(), dst.into());
         self.check_grid_bounds(src)?;
         self.check_grid_bounds(dst)?;
-        let prev = *self.get_mut(src).unwrap();
 
         let destv = self.get(dst)?;
         for rule in rules {
-            rule(dst, destv)?;
+            rule(dst.into(), destv)?;
         }
 
-        self.set(src, &self.initial_value.clone())?;
-        self.set(dst, &prev)?;
-
-        Ok(())
+        self.mov(src, dst)
     }
 
-    /// Moves a given value from position (x, y) to another position based on the direction
+    /// Moves a given value from position (row, col) to another position based on the direction
     ///
-    /// The directions can be Left, Right, Top, Down:
+    /// The directions can be Left, Right, Up, Down and the diagonals:
     /// * DasGrid::MoveDirection::Left, translates to (0, -1)
     /// * DasGrid::MoveDirection::Right, translates to (0, 1)
-    /// * DasGrid::MoveDirection::Top, translates to (-1, 0)
+    /// * DasGrid::MoveDirection::Up, translates to (-1, 0)
     /// * DasGrid::MoveDirection::Down, translates to (1, 0)
+    /// * DasGrid::MoveDirection::UpLeft, translates to (-1, -1)
+    /// * DasGrid::MoveDirection::UpRight, translates to (-1, 1)
+    /// * DasGrid::MoveDirection::DownLeft, translates to (1, -1)
+    /// * DasGrid::MoveDirection::DownRight, translates to (1, 1)
     ///
     /// Be careful if the value is out of the bounds of grid it will return an error
     /// with the type of GridErr::OutOfGrid
     ///
+    /// The value that was on the destiny is returned, as `Grid::mov` does
+    ///
     /// ```.rust
-    /// let mut grid = das_grid::Grid::new((2, 2),(1., 1.), 1);
-    /// assert_eq!(grid.mov_to((0, 0), das_grid::MoveDirection::Right), Ok(()));
+    /// let mut grid = das_grid::Grid::new((2, 2),(1., 1.), 0);
+    /// grid.set((0, 0), 1);
+    /// assert_eq!(grid.mov_to((0, 0), das_grid::MoveDirection::Right), Ok(0));
+    /// assert_eq!(grid.mov_to((0, 1), das_grid::MoveDirection::DownLeft), Ok(0));
+    /// assert_eq!(grid.get((1, 0)), Ok(&1));
     /// ```
-    pub fn mov_to(&mut self, src: (i32, i32), dst_direction: MoveDirection) -> Result<(), GridErr> {
-        let (x, y) = src;
-        self.check_grid_bounds(src)?;
-
-        let (xx, yy) = match dst_direction {
-            MoveDirection::Up => MOVE_UP,
-            MoveDirection::Down => MOVE_DOWN,
-            MoveDirection::Left => MOVE_LEFT,
-            MoveDirection::Right => MOVE_RIGHT,
-        };
-
-        let dest = (x + xx, y + yy);
-        self.check_grid_bounds(dest)?;
-
-        let prev = *self.get_mut(src).unwrap();
-        self.set(src, &self.initial_value.clone())?;
-        self.set(dest, &prev)?;
-
-        Ok(())
+    pub fn mov_to(
+        &mut self,
+        src: impl Into<Pos>,
+        dst_direction: MoveDirection,
+    ) -> Result<T, GridErr>
+    where
+        T: Clone,
+    {
+        self.mov_by(src, dst_direction)
     }
 
-    /// Moves a given value from position (x, y) to another position based on the direction
+    /// Moves a given value from position (row, col) to another position based on the direction
     /// Only if no rule return error
     ///
-    /// if the dest x, y grid is out of bounds it return error GridErr::OutOfGrid
+    /// if the dest position is out of bounds it return error GridErr::OutOfGrid
     ///
     /// And if a rule some rule failed it will return GridErr::RuleFailed
     ///
-    /// The directions can be Left, Right, Top, Down:
-    /// * DasGrid::MoveDirection::Left, translates to (0, -1)
-    /// * DasGrid::MoveDirection::Right, translates to (0, 1)
-    /// * DasGrid::MoveDirection::Top, translates to (-1, 0)
-    /// * DasGrid::MoveDirection::Down, translates to (1, 0)
-    ///
-    /// Be careful if the value is out of the bounds of grid it will return an error
-    /// with the type of GridErr::OutOfGrid
+    /// The directions are the same of `Grid::mov_to`
     ///
     /// ```.rust
     /// let mut g = das_grid::Grid::new((2, 2),(1., 1.), 0);
-    /// g.set((0, 1), &1);
+    /// g.set((0, 1), 1);
     /// let rule_not_1 = |_: (i32, i32), value: &i32| -> Result<(), das_grid::GridErr> {
     ///     if *value == 1 {
-    ///         return Err(das_grid::GridErr::RuleFailed);
+    ///         return Err(das_grid::GridErr::RuleFailed(None));
     ///     }
     ///     Ok(())
     /// };
@@ -652,36 +1102,74 @@ impl<T: Copy + Clone> Grid<T> {
     /// ```
     pub fn mov_to_with_rules<R>(
         &mut self,
-        src: (i32, i32),
+        src: impl Into<Pos>,
         dst_direction: MoveDirection,
         rules: Vec<R>,
-    ) -> Result<(), GridErr>
+    ) -> Result<T, GridErr>
     where
+        T: Clone,
         R: Fn((i32, i32), &T) -> Result<(), GridErr>,
     {
-        let (x, y) = src;
-        self.check_grid_bounds(src)?;
-
-        let (xx, yy) = match dst_direction {
-            MoveDirection::Up => MOVE_UP,
-            MoveDirection::Down => MOVE_DOWN,
-            MoveDirection::Left => MOVE_LEFT,
-            MoveDirection::Right => MOVE_RIGHT,
-        };
-
-        let dest = (x + xx, y + yy);
-        self.check_grid_bounds(dest)?;
-
-        let destv = self.get(dest)?;
-        for rule in rules {
-            rule(dest, destv)?;
-        }
+        self.mov_by_with_rules(src, dst_direction, rules)
+    }
 
-        let prev = *self.get_mut(src).unwrap();
-        self.set(src, &self.initial_value.clone())?;
-        self.set(dest, &prev)?;
+    /// Moves a given value from position (row, col) to the position at the given offset,
+    /// like a knight jump `(2, 1)` on a chess board
+    ///
+    /// Be careful if the value is out of the bounds of grid it will return an error
+    /// with the type of GridErr::OutOfGrid
+    ///
+    /// ```.rust
+    /// let mut grid = das_grid::Grid::new((8, 8), (1., 1.), 0);
+    /// grid.set((0, 1), 1);
+    /// assert_eq!(grid.mov_by((0, 1), (2, 1)), Ok(0));
+    /// assert_eq!(grid.get((2, 2)), Ok(&1));
+    /// assert_eq!(
+    ///     grid.mov_by((2, 2), (-3, 0)),
+    ///     Err(das_grid::GridErr::OutOfGrid { pos: das_grid::Pos::new(-1, 2) })
+    /// );
+    /// ```
+    pub fn mov_by(&mut self, src: impl Into<Pos>, offset: impl Into<Offset>) -> Result<T, GridErr>
+    where
+        T: Clone,
+    {
+        let src = src.into();
+        self.check_grid_bounds(src)?;
+        self.mov(src, src + offset.into())
+    }
 
-        Ok(())
+    /// Moves a given value from position (row, col) to the position at the given offset
+    /// Only if no rule return error
+    ///
+    /// if the dest position is out of bounds it return error GridErr::OutOfGrid
+    ///
+    /// And if a rule some rule failed it will return GridErr::RuleFailed
+    ///
+    /// ```.rust
+    /// let mut g = das_grid::Grid::new((3, 3), (1., 1.), 0);
+    /// g.set((2, 1), 1);
+    /// let rule_not_1 = |_: (i32, i32), value: &i32| -> Result<(), das_grid::GridErr> {
+    ///     if *value == 1 {
+    ///         return Err(das_grid::GridErr::rule_failed("tile occupied"));
+    ///     }
+    ///     Ok(())
+    /// };
+    /// let ret = g.mov_by_with_rules((0, 0), (2, 1), vec![rule_not_1]);
+    /// assert_eq!(ret, Err(das_grid::GridErr::rule_failed("tile occupied")));
+    /// ```
+    pub fn mov_by_with_rules<R>(
+        &mut self,
+        src: impl Into<Pos>,
+        offset: impl Into<Offset>,
+        rules: Vec<R>,
+    ) -> Result<T, GridErr>
+    where
+        T: Clone,
+        R: Fn((i32, i32), &T) -> Result<(), GridErr>,
+    {
+        let src = src.into();
+        self.check_grid_bounds(src)?;
+        self.mov_with_rules(src, src + offset.into(), rules)
     }
 
     /// Get the size of grid based on cells length
@@ -714,54 +1202,45 @@ impl<T: Copy + Clone> Grid<T> {
         self.cols
     }
 
-    /// Returns the grid as a tuple of (x, y)
+    /// Returns the grid as a tuple of (row, col)
     ///
     /// ```.rust
     /// let mut grid = das_grid::Grid::new((3, 2), (1., 1.), 1);
-    /// for (x, y) in grid.enumerate() {
-    ///     println!("x {} y {}", x, y);
+    /// for (row, col) in grid.enumerate() {
+    ///     println!("row {} col {}", row, col);
     /// }
     /// ```
     pub fn enumerate(&self) -> Vec<(i32, i32)> {
-        let mut x = 0;
-        let mut y = 0;
-        self.cells
-            .iter()
-            .enumerate()
-            .map(|(idx, _)| {
-                if idx as i32 % self.rows() == 0 && idx > 1 {
-                    y = 0;
-                    x += 1;
-                }
-                let res = (x, y);
-                y += 1;
-                res
-            })
-            .collect::<Vec<_>>()
+        self.positions().map(|pos| pos.into()).collect::<Vec<_>>()
     }
 
-    /// Returns the grid as a tuple of (x, y) but with cell size calculated
+    /// Returns an iterator over the positions of the grid, row by row
+    ///
+    /// ```.rust
+    /// let grid = das_grid::Grid::new((2, 3), (1., 1.), 1);
+    /// let last = grid.positions().last();
+    /// assert_eq!(last, Some(das_grid::Pos::new(1, 2)));
+    /// ```
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.size() as i32).map(move |idx| Pos::new(idx / cols, idx % cols))
+    }
+
+    /// Returns the grid as a tuple of (row, col) but with cell size calculated
     ///
     /// ```.rust
     /// let mut grid = das_grid::Grid::new((3, 2), (1., 1.), 1);
-    /// for (x, y) in grid.enumerate() {
+    /// for (x, y) in grid.enumerate_to_cell_size() {
     ///     println!("x {} y {}", x, y);
     /// }
     /// ```
     pub fn enumerate_to_cell_size(&self) -> Vec<(f32, f32)> {
-        let mut x = 0;
-        let mut y = 0;
-        self.cells
-            .iter()
-            .enumerate()
-            .map(|(idx, _)| {
-                if idx as i32 % self.rows() == 0 && idx > 1 {
-                    y = 0;
-                    x += 1;
-                }
-                let res = (x as f32 * self.cell_size.0, y as f32 * self.cell_size.1);
-                y += 1;
-                res
+        self.positions()
+            .map(|pos| {
+                (
+                    pos.row as f32 * self.cell_size.0,
+                    pos.col as f32 * self.cell_size.1,
+                )
             })
             .collect::<Vec<_>>()
     }
@@ -775,11 +1254,14 @@ impl<T: Copy + Clone> Grid<T> {
     /// let col = g.get_col(1).unwrap();
     /// assert_eq!(col, vec![2, 4]);
     /// ```
-    pub fn get_col(&self, col_idx: i32) -> Result<Vec<T>, GridErr> {
+    pub fn get_col(&self, col_idx: i32) -> Result<Vec<T>, GridErr>
+    where
+        T: Clone,
+    {
         let mut vec_result: Vec<T> = vec![];
-        for idx in (0..self.cols).into_iter() {
+        for idx in 0..self.rows {
             let v = self.get((idx, col_idx))?;
-            vec_result.push(*v);
+            vec_result.push(v.clone());
         }
         Ok(vec_result)
     }
@@ -793,22 +1275,80 @@ impl<T: Copy + Clone> Grid<T> {
     /// let row = g.get_row(1).unwrap();
     /// assert_eq!(row, vec![3, 4]);
     /// ```
-    pub fn get_row(&self, row_idx: i32) -> Result<Vec<T>, GridErr> {
+    pub fn get_row(&self, row_idx: i32) -> Result<Vec<T>, GridErr>
+    where
+        T: Clone,
+    {
         let mut vec_result: Vec<T> = vec![];
-        for idx in (0..self.rows).into_iter() {
+        for idx in 0..self.cols {
             let v = self.get((row_idx, idx))?;
-            vec_result.push(*v);
+            vec_result.push(v.clone());
         }
         Ok(vec_result)
     }
 
+    /// Sets the values of the col, from the first row to the last
+    ///
+    /// If the col idx is wrong it can return the error GridErr::OutOfGrid
+    ///
+    /// And if the amount of values isn't the rows of the grid it returns the error GridErr::SizeMismatch
+    ///
+    /// ```.rust
+    /// let mut g = das_grid::Grid::new_from_vector((2, 2), (1.,1.), vec![1, 2, 3, 4]);
+    /// g.set_col(1, vec![5, 6]).unwrap();
+    /// assert_eq!(g.get_flatten_grid(), vec![1, 5, 3, 6]);
+    /// ```
+    pub fn set_col(&mut self, col_idx: i32, values: Vec<T>) -> Result<(), GridErr> {
+        self.check_grid_bounds(Pos::new(0, col_idx))?;
+        if values.len() != self.rows as usize {
+            return Err(GridErr::SizeMismatch {
+                expected: self.rows as usize,
+                actual: values.len(),
+            });
+        }
+
+        for (idx, v) in values.into_iter().enumerate() {
+            self.set((idx as i32, col_idx), v)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the values of the row, from the first col to the last
+    ///
+    /// If the row idx is wrong it can return the error GridErr::OutOfGrid
+    ///
+    /// And if the amount of values isn't the cols of the grid it returns the error GridErr::SizeMismatch
+    ///
+    /// ```.rust
+    /// let mut g = das_grid::Grid::new_from_vector((2, 2), (1.,1.), vec![1, 2, 3, 4]);
+    /// g.set_row(0, vec![5, 6]).unwrap();
+    /// assert_eq!(g.get_flatten_grid(), vec![5, 6, 3, 4]);
+    /// ```
+    pub fn set_row(&mut self, row_idx: i32, values: Vec<T>) -> Result<(), GridErr> {
+        self.check_grid_bounds(Pos::new(row_idx, 0))?;
+        if values.len() != self.cols as usize {
+            return Err(GridErr::SizeMismatch {
+                expected: self.cols as usize,
+                actual: values.len(),
+            });
+        }
+
+        for (idx, v) in values.into_iter().enumerate() {
+            self.set((row_idx, idx as i32), v)?;
+        }
+        Ok(())
+    }
+
     /// Returns a clone of the internal representation of the grid
     ///
     /// ```.rust
     /// let mut g = das_grid::Grid::new_from_vector((2, 2), (1.,1.), vec![1, 2, 3, 4]);
     /// assert_eq!(g.get_flatten_grid(), vec![1,2,3,4]);
     /// ```
-    pub fn get_flatten_grid(&self) -> Vec<T> {
+    pub fn get_flatten_grid(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
         self.cells.clone()
     }
 
@@ -817,7 +1357,10 @@ impl<T: Copy + Clone> Grid<T> {
     /// ```.rust
     ///
     /// ```
-    pub fn fill_grid(&mut self, value: T) {
+    pub fn fill_grid(&mut self, value: T)
+    where
+        T: Clone,
+    {
         self.cells.fill(value);
     }
 
@@ -843,20 +1386,22 @@ impl<T: Copy + Clone> Grid<T> {
     /// ```
     pub fn fill_subgrid(
         &mut self,
-        dst: (i32, i32),
+        dst: impl Into<Pos>,
         frame_size: (i32, i32),
         value: &T,
-    ) -> Result<Grid<T>, GridErr> {
+    ) -> Result<Grid<T>, GridErr>
+    where
+        T: Clone,
+    {
+        let dst = dst.into();
         self.check_grid_bounds(dst)?;
-        let sub_grid = Grid::new(frame_size, self.cell_size, self.initial_value);
+        let sub_grid = Grid::try_new(frame_size, self.cell_size, self.initial_value.clone())?;
         self.check_grid_overflow(&sub_grid)?;
 
-        for sub_index in sub_grid.enumerate() {
-            let dest = (dst.0 + sub_index.0, dst.1 + sub_index.1);
-            match self.set(dest, value) {
-                Ok(_) => (),
-                _ => (),
-            }
+        for sub_index in sub_grid.positions() {
+            let dest = dst + Offset::from(sub_index);
+            // Ok if the area bleeds
+            let _ = self.set(dest, value.clone());
         }
 
         Ok(sub_grid)
@@ -873,7 +1418,7 @@ impl<T: Copy + Clone> Grid<T> {
     }
 }
 
-impl<'a, T: Copy + Clone> IntoIterator for &'a Grid<T> {
+impl<'a, T> IntoIterator for &'a Grid<T> {
     type Item = &'a T;
     type IntoIter = std::slice::Iter<'a, T>;
     fn into_iter(self) -> Self::IntoIter {
@@ -881,7 +1426,7 @@ impl<'a, T: Copy + Clone> IntoIterator for &'a Grid<T> {
     }
 }
 
-impl<'a, T: Copy + Clone> IntoIterator for &'a mut Grid<T> {
+impl<'a, T> IntoIterator for &'a mut Grid<T> {
     type Item = &'a mut T;
     type IntoIter = std::slice::IterMut<'a, T>;
     fn into_iter(self) -> Self::IntoIter {
@@ -889,7 +1434,7 @@ impl<'a, T: Copy + Clone> IntoIterator for &'a mut Grid<T> {
     }
 }
 
-impl<T: Copy + Clone> fmt::Display for Grid<T> {
+impl<T> fmt::Display for Grid<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
@@ -899,19 +1444,15 @@ impl<T: Copy + Clone> fmt::Display for Grid<T> {
     }
 }
 
-impl<T: Copy + Clone + Display> fmt::Debug for Grid<T> {
+impl<T: Display> fmt::Debug for Grid<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut cell_str = String::new();
 
-        let mut pos = (0, 0);
-        for (idx, cell) in self.cells.iter().enumerate() {
-            if idx as i32 % self.cols == 0 && idx > 0 {
-                pos.1 = 0;
-                pos.0 += 1;
+        for (pos, cell) in self.positions().zip(self.cells.iter()) {
+            if pos.col == 0 && pos.row > 0 {
                 cell_str += "\n";
             }
-            cell_str.push_str(&format!("\t{:3} (x: {} y: {})", cell, pos.0, pos.1));
-            pos.1 += 1
+            cell_str.push_str(&format!("\t{:3} {}", cell, pos));
         }
 
         write!(
@@ -922,18 +1463,69 @@ impl<T: Copy + Clone + Display> fmt::Debug for Grid<T> {
     }
 }
 
-impl<T: Copy + Clone> Index<(i32, i32)> for Grid<T> {
+impl<T, P: Into<Pos>> Index<P> for Grid<T> {
     type Output = T;
-    fn index(&self, index: (i32, i32)) -> &T {
+    fn index(&self, index: P) -> &T {
         self.get(index).unwrap()
     }
 }
 
-impl<T: Copy + Clone> IndexMut<(i32, i32)> for Grid<T> {
-    fn index_mut(&mut self, index: (i32, i32)) -> &mut T {
+impl<T, P: Into<Pos>> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, index: P) -> &mut T {
         self.get_mut(index).unwrap()
     }
 }
 
 #[cfg(test)]
 mod lib_test;
+
+#[cfg(test)]
+mod automaton_test;
+
+#[cfg(test)]
+mod chunked_test;
+
+#[cfg(test)]
+mod direction_test;
+
+#[cfg(test)]
+mod gravity_test;
+
+#[cfg(test)]
+mod hex_test;
+
+#[cfg(test)]
+mod moves_test;
+
+#[cfg(test)]
+mod neighbors_test;
+
+#[cfg(test)]
+mod pathfinding_test;
+
+#[cfg(test)]
+mod pattern_test;
+
+#[cfg(test)]
+mod pos_test;
+
+#[cfg(test)]
+mod region_test;
+
+#[cfg(test)]
+mod rules_test;
+
+#[cfg(test)]
+mod sight_test;
+
+#[cfg(test)]
+mod topology_test;
+
+#[cfg(test)]
+mod traits_test;
+
+#[cfg(test)]
+mod transform_test;
+
+#[cfg(test)]
+mod view_test;
@@ -7,7 +7,7 @@ use std::{
     fmt::{self},
 };
 
-use crate::{Grid, GridErr, MoveDirection};
+use crate::{Grid, GridErr, MoveDirection, Pos, Topology, Transform};
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 enum Pawn {
@@ -43,7 +43,7 @@ fn test_create_grid_with_enum() {
     let mut g: Grid<Pawn> = Grid::new((10, 10), (1., 1.), Pawn::None);
     assert_eq!(g.get((0, 0)).unwrap(), &Pawn::None);
 
-    g.set((5, 5), &Pawn::Player);
+    g.set((5, 5), Pawn::Player);
     assert_eq!(g.mov_to((5, 5), MoveDirection::Right).is_ok(), true);
 }
 
@@ -79,7 +79,7 @@ fn test_set_value() {
     let mut g = Grid::new((2, 2), (1., 1.), 1);
     let p = g.get_mut((0, 0)).unwrap();
     *p = 50;
-    g.set((0, 0), &2);
+    g.set((0, 0), 2);
     assert_eq!(g.get((0, 0)).unwrap(), &2);
 }
 
@@ -98,35 +98,60 @@ fn test_mov_cell() {
 #[test]
 fn test_move_to() {
     let mut g = Grid::new((2, 2), (1., 1.), 0);
-    g.set((0, 0), &1);
+    g.set((0, 0), 1);
 
     let ret = g.mov_to((0, 0), MoveDirection::Right);
     assert_eq!(g.get((0, 1)).unwrap(), &1);
     assert_eq!(ret.is_ok(), true);
 
     let ret = g.mov_to((0, 1), MoveDirection::Right);
-    assert_eq!(ret.unwrap_err(), GridErr::OutOfGrid);
+    assert_eq!(
+        ret.unwrap_err(),
+        GridErr::OutOfGrid {
+            pos: Pos::new(0, 2)
+        }
+    );
 
     let ret = g.mov_to((0, 1), MoveDirection::Left);
     assert_eq!(g.get((0, 0)).unwrap(), &1);
 
     let ret = g.mov_to((0, 0), MoveDirection::Left);
-    assert_eq!(ret.unwrap_err(), GridErr::OutOfGrid);
+    assert_eq!(
+        ret.unwrap_err(),
+        GridErr::OutOfGrid {
+            pos: Pos::new(0, -1)
+        }
+    );
 
     let ret = g.mov_to((0, 0), MoveDirection::Up);
-    assert_eq!(ret.unwrap_err(), GridErr::OutOfGrid);
+    assert_eq!(
+        ret.unwrap_err(),
+        GridErr::OutOfGrid {
+            pos: Pos::new(-1, 0)
+        }
+    );
 
     let ret = g.mov_to((0, 0), MoveDirection::Down);
     assert_eq!(ret.is_ok(), true);
 
     let ret = g.mov_to((1, 0), MoveDirection::Down);
-    assert_eq!(ret.unwrap_err(), GridErr::OutOfGrid);
+    assert_eq!(
+        ret.unwrap_err(),
+        GridErr::OutOfGrid {
+            pos: Pos::new(2, 0)
+        }
+    );
 
     let ret = g.mov_to((1, 0), MoveDirection::Right);
     assert_eq!(ret.is_ok(), true);
 
     let ret = g.mov_to((1, 1), MoveDirection::Right);
-    assert_eq!(ret.unwrap_err(), GridErr::OutOfGrid);
+    assert_eq!(
+        ret.unwrap_err(),
+        GridErr::OutOfGrid {
+            pos: Pos::new(1, 2)
+        }
+    );
 }
 
 #[test]
@@ -155,20 +180,105 @@ fn test_iterators() {
 #[test]
 fn test_set_with_rules() {
     let mut grid: Grid<i32> = Grid::new((2, 2), (1., 1.), 0);
-    assert_eq!(grid.set((0, 1), &1).is_ok(), true);
+    assert_eq!(grid.set((0, 1), 1).is_ok(), true);
 
     let rule_not_1 = |_: (i32, i32), value: &i32| -> Result<(), GridErr> {
         if *value == 1 {
-            return Err(GridErr::RuleFailed);
+            return Err(GridErr::RuleFailed(None));
         }
         Ok(())
     };
 
     assert_eq!(
-        grid.set_with_rules((0, 1), &1, vec![rule_not_1])
+        grid.set_with_rules((0, 1), 1, vec![rule_not_1])
             .err()
             .unwrap(),
-        GridErr::RuleFailed
+        GridErr::RuleFailed(None)
+    );
+}
+
+#[test]
+fn test_rule_failed_reason() {
+    #[derive(Debug, PartialEq)]
+    enum Why {
+        Occupied(i32),
+        NotYourTurn,
+    }
+
+    impl fmt::Display for Why {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                Why::Occupied(v) => write!(f, "tile occupied by {}", v),
+                Why::NotYourTurn => write!(f, "not your turn"),
+            }
+        }
+    }
+
+    impl std::error::Error for Why {}
+
+    let occupied = |_: (i32, i32), value: &i32| -> Result<(), GridErr> {
+        if *value != 0 {
+            return Err(GridErr::rule_failed(Why::Occupied(*value)));
+        }
+        Ok(())
+    };
+
+    let mut g = Grid::new_from_vector((2, 2), (1., 1.), vec![0, 3, 0, 0]);
+    let err = g
+        .mov_with_rules((0, 0), (0, 1), vec![occupied])
+        .unwrap_err();
+    assert_eq!(err.reason::<Why>(), Some(&Why::Occupied(3)));
+    assert_eq!(
+        err.to_string(),
+        "failed to meet the rule requirements: tile occupied by 3"
+    );
+    assert_eq!(err, GridErr::rule_failed(Why::Occupied(3)));
+    assert_ne!(err, GridErr::rule_failed(Why::NotYourTurn));
+    assert_ne!(err, GridErr::RuleFailed(None));
+
+    let sub_grid = Grid::new((2, 2), (1., 1.), 1);
+    let err = g.stamp_subgrid_with_rules((0, 0), sub_grid, vec![occupied]);
+    assert_eq!(err.unwrap_err().reason::<Why>(), Some(&Why::Occupied(3)));
+
+    let turn = |_: (i32, i32), _: &i32| -> Result<(), GridErr> {
+        Err(GridErr::rule_failed("not your turn"))
+    };
+    let err = g.set_with_rules((1, 1), 5, vec![turn]).unwrap_err();
+    assert_eq!(err.reason::<Why>(), None);
+    assert_eq!(
+        err.to_string(),
+        "failed to meet the rule requirements: not your turn"
+    );
+    assert_eq!(g.get((1, 1)), Ok(&0));
+}
+
+#[test]
+fn test_error_context() {
+    let mut g = Grid::new((3, 4), (1., 1.), 0);
+    assert_eq!(
+        g.set((3, 1), 1),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(3, 1)
+        })
+    );
+
+    let err = g
+        .stamp_subgrid((0, 0), Grid::new((4, 2), (1., 1.), 1))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        GridErr::SubgridOverflow {
+            sub: (4, 2),
+            parent: (3, 4)
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "the subgrid 4x2 is greater than the parent grid 3x4"
+    );
+    assert_eq!(
+        g.get((-1, 2)).unwrap_err().to_string(),
+        "the position (row: -1 col: 2) is out of the grid rows and cols"
     );
 }
 
@@ -190,7 +300,7 @@ fn test_stamp_subgrid_with_rules_1() {
 
     let rule_not_1 = |_: (i32, i32), value: &i32| -> Result<(), GridErr> {
         if *value == 1 {
-            return Err(GridErr::RuleFailed);
+            return Err(GridErr::RuleFailed(None));
         }
         Ok(())
     };
@@ -202,6 +312,125 @@ fn test_stamp_subgrid_with_rules_1() {
     );
 }
 
+#[test]
+fn test_stamp_subgrid_transformed() {
+    let mut grid = Grid::new((4, 4), (1., 1.), '.');
+    let piece = Grid::new_from_vector((2, 3), (1., 1.), vec!['T', 'T', 'T', ' ', 'T', ' ']);
+
+    // The spaces don't erase what is underneath
+    grid.set((3, 0), '#');
+    let touched = grid
+        .stamp_subgrid_transformed((1, 0), piece, Transform::Rotate90Cw, |_, v| *v == ' ')
+        .unwrap();
+    assert_eq!(touched.len(), 4);
+    assert!(touched.contains(&Pos::new(2, 1)));
+    assert_eq!(grid.get_col(0).unwrap(), vec!['.', '.', 'T', '#']);
+    assert_eq!(grid.get_col(1).unwrap(), vec!['.', 'T', 'T', 'T']);
+
+    // The mask follows the subgrid positions and the bleeding cells aren't reported
+    let mut grid = Grid::new((3, 3), (1., 1.), 0);
+    let sub = Grid::new((2, 2), (1., 1.), 5);
+    let mask = Grid::new_from_vector((2, 2), (1., 1.), vec![true, false, true, true]);
+    let touched = grid
+        .stamp_subgrid_transformed((2, 2), sub, Transform::Identity, |pos, _| !mask[pos])
+        .unwrap();
+    assert_eq!(touched, vec![Pos::new(2, 2)]);
+
+    // On a wrapping grid the positions are the wrapped cells that were written
+    let mut grid = Grid::new((3, 3), (1., 1.), 0);
+    grid.set_topology(Topology::Torus);
+    let sub = Grid::new((2, 2), (1., 1.), 5);
+    let touched = grid
+        .stamp_subgrid_transformed((2, 2), sub, Transform::Identity, |_, _| false)
+        .unwrap();
+    assert_eq!(
+        touched,
+        vec![
+            Pos::new(2, 2),
+            Pos::new(2, 0),
+            Pos::new(0, 2),
+            Pos::new(0, 0)
+        ]
+    );
+    assert!(touched.iter().all(|pos| grid[*pos] == 5));
+
+    let tall = Grid::new((4, 1), (1., 1.), 1);
+    assert_eq!(
+        grid.stamp_subgrid_transformed((0, 0), tall, Transform::Rotate90Cw, |_, _| false),
+        Err(GridErr::SubgridOverflow {
+            sub: (1, 4),
+            parent: (3, 3)
+        })
+    );
+}
+
+#[test]
+fn test_stamp_subgrid_with_rules_is_all_or_nothing() {
+    let mut grid = Grid::new((3, 3), (1., 1.), 0);
+    grid.set((1, 1), 9);
+    let sub_grid = Grid::new((2, 2), (1., 1.), 1);
+
+    let rule_empty = |_: (i32, i32), value: &i32| -> Result<(), GridErr> {
+        if *value != 0 {
+            return Err(GridErr::RuleFailed(None));
+        }
+        Ok(())
+    };
+
+    assert_eq!(
+        grid.stamp_subgrid_with_rules((0, 0), sub_grid, vec![rule_empty]),
+        Err(GridErr::RuleFailed(None))
+    );
+    assert_eq!(grid.get_flatten_grid(), vec![0, 0, 0, 0, 9, 0, 0, 0, 0]);
+
+    // Bleeding fails before anything is stamped too
+    let sub_grid = Grid::new((2, 2), (1., 1.), 1);
+    assert_eq!(
+        grid.stamp_subgrid_with_rules((2, 2), sub_grid, vec![rule_empty]),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(2, 3)
+        })
+    );
+    assert_eq!(grid.get((2, 2)), Ok(&0));
+}
+
+#[test]
+fn test_can_stamp_and_try_stamp() {
+    let mut grid =
+        Grid::new_from_vector((3, 4), (1., 1.), vec![0, 0, 0, 0, 0, 0, 1, 0, 1, 1, 1, 1]);
+    let piece = Grid::new_from_vector((2, 2), (1., 1.), vec![2, 0, 2, 2]);
+
+    // Empty cells of the piece never collide, out of the grid always does
+    let collides = |cur: Option<&i32>, new: &i32| *new != 0 && cur.map_or(true, |c| *c != 0);
+
+    assert_eq!(grid.can_stamp((0, 0), &piece, collides), vec![]);
+    assert_eq!(
+        grid.can_stamp((1, 1), &piece, collides),
+        vec![Pos::new(2, 1), Pos::new(2, 2)]
+    );
+    assert_eq!(
+        grid.can_stamp((0, 3), &piece, collides),
+        vec![Pos::new(1, 4)]
+    );
+    assert_eq!(grid.can_stamp((5, 5), &piece, collides).len(), 3);
+
+    let before = grid.get_flatten_grid();
+    assert_eq!(
+        grid.try_stamp((1, 2), piece, collides),
+        Err(GridErr::Collision {
+            positions: vec![Pos::new(1, 2), Pos::new(2, 2), Pos::new(2, 3)]
+        })
+    );
+    assert_eq!(grid.get_flatten_grid(), before);
+
+    let piece = Grid::new_from_vector((2, 2), (1., 1.), vec![2, 0, 2, 2]);
+    assert_eq!(grid.try_stamp((0, 0), piece, collides), Ok(()));
+    assert_eq!(
+        grid.get_flatten_grid(),
+        vec![2, 0, 0, 0, 2, 2, 1, 0, 1, 1, 1, 1]
+    );
+}
+
 #[test]
 fn test_get_row() {
     let mut g = Grid::new_from_vector((2, 2), (1., 1.), vec![1, 2, 3, 4]);
@@ -227,11 +456,11 @@ fn test_get_subgrid() {
 #[test]
 fn test_mov_to_with_rules() {
     let mut g = Grid::new((2, 2), (1., 1.), 0);
-    g.set((0, 1), &1);
+    g.set((0, 1), 1);
 
     let rule_not_1 = |_: (i32, i32), value: &i32| -> Result<(), GridErr> {
         if *value == 1 {
-            return Err(GridErr::RuleFailed);
+            return Err(GridErr::RuleFailed(None));
         }
         Ok(())
     };
@@ -249,3 +478,265 @@ fn test_fill_subgrid() {
     assert_eq!(grid.get((2, 1)).unwrap(), &0);
     assert_eq!(grid.get((2, 2)).unwrap(), &0);
 }
+
+#[test]
+fn test_rectangular_grid_addressing() {
+    for rows in 1..=12 {
+        for cols in 1..=12 {
+            let mut g = Grid::new((rows, cols), (1., 1.), -1);
+
+            // Every position writes into a distinct cell
+            for x in 0..rows {
+                for y in 0..cols {
+                    assert_eq!(g.get((x, y)).unwrap(), &-1, "aliased at ({}, {})", x, y);
+                    g.set((x, y), x * cols + y).unwrap();
+                }
+            }
+
+            // And every cell is read back from the same position
+            for x in 0..rows {
+                for y in 0..cols {
+                    assert_eq!(g.get((x, y)).unwrap(), &(x * cols + y));
+                }
+            }
+            assert_eq!(g.get_flatten_grid(), (0..rows * cols).collect::<Vec<_>>());
+
+            assert_eq!(
+                g.get((rows, 0)),
+                Err(GridErr::OutOfGrid {
+                    pos: Pos::new(rows, 0)
+                })
+            );
+            assert_eq!(
+                g.get((0, cols)),
+                Err(GridErr::OutOfGrid {
+                    pos: Pos::new(0, cols)
+                })
+            );
+        }
+    }
+}
+
+#[test]
+fn test_rectangular_grid_enumerate() {
+    for rows in 1..=12 {
+        for cols in 1..=12 {
+            let g = Grid::new((rows, cols), (2., 3.), 0);
+            let positions = g.enumerate();
+            assert_eq!(positions.len(), g.size());
+
+            let mut expected = vec![];
+            for x in 0..rows {
+                for y in 0..cols {
+                    expected.push((x, y));
+                }
+            }
+            assert_eq!(positions, expected);
+
+            let scaled = g.enumerate_to_cell_size();
+            for ((x, y), (sx, sy)) in positions.iter().zip(scaled) {
+                assert_eq!((*x as f32 * 2., *y as f32 * 3.), (sx, sy));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rectangular_grid_rows_and_cols() {
+    let mut g = Grid::new((3, 4), (1., 1.), 0);
+    for (x, y) in g.enumerate() {
+        g.set((x, y), x * 4 + y + 1).unwrap();
+    }
+
+    assert_eq!(g.get_row(0).unwrap(), vec![1, 2, 3, 4]);
+    assert_eq!(g.get_row(2).unwrap(), vec![9, 10, 11, 12]);
+    assert_eq!(
+        g.get_row(3),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(3, 0)
+        })
+    );
+    assert_eq!(g.get_col(0).unwrap(), vec![1, 5, 9]);
+    assert_eq!(g.get_col(3).unwrap(), vec![4, 8, 12]);
+    assert_eq!(
+        g.get_col(4),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(0, 4)
+        })
+    );
+
+    let sub_grid = g.get_subgrid((1, 1), 2, 3).unwrap();
+    assert_eq!(sub_grid.get_flatten_grid(), vec![6, 7, 8, 10, 11, 12]);
+
+    let mut g = Grid::new((9, 16), (1., 1.), 0);
+    let sub_grid = Grid::new((2, 3), (1., 1.), 1);
+    assert!(g.stamp_subgrid((7, 13), sub_grid).is_ok());
+    assert_eq!(g.get_flatten_grid().iter().sum::<i32>(), 6);
+    assert_eq!(g.get((8, 15)).unwrap(), &1);
+
+    assert!(g.fill_subgrid((0, 0), (9, 1), &2).is_ok());
+    assert_eq!(g.get_col(0).unwrap(), vec![2; 9]);
+    assert_eq!(g.get_row(0).unwrap().iter().filter(|v| **v == 2).count(), 1);
+
+    let g = Grid::new((2, 3), (1., 1.), 0);
+    assert_eq!(
+        format!("{:?}", g),
+        "Grid { rows: 2, cols: 3, cells: [\n\
+         \t  0 (row: 0 col: 0)\t  0 (row: 0 col: 1)\t  0 (row: 0 col: 2)\n\
+         \t  0 (row: 1 col: 0)\t  0 (row: 1 col: 1)\t  0 (row: 1 col: 2)\n] }"
+    );
+}
+
+#[test]
+fn test_try_new() {
+    let g = Grid::try_new((16, 9), (1., 1.), 0).unwrap();
+    assert_eq!((g.rows(), g.cols(), g.size()), (16, 9, 144));
+
+    assert_eq!(
+        Grid::try_new((0, 0), (1., 1.), 0).err(),
+        Some(GridErr::InvalidSize { rows: 0, cols: 0 })
+    );
+    assert_eq!(
+        Grid::try_new((-2, -2), (1., 1.), 0).err(),
+        Some(GridErr::InvalidSize { rows: -2, cols: -2 })
+    );
+    assert_eq!(
+        Grid::try_new((3, -1), (1., 1.), 0).err(),
+        Some(GridErr::InvalidSize { rows: 3, cols: -1 })
+    );
+    assert_eq!(
+        Grid::try_new((i32::MAX, 2), (1., 1.), 0).err(),
+        Some(GridErr::InvalidSize {
+            rows: i32::MAX,
+            cols: 2
+        })
+    );
+}
+
+#[test]
+fn test_try_from_vec() {
+    let g = Grid::try_from_vec((3, 3), (1., 1.), (1..=9).collect()).unwrap();
+    assert_eq!(g.get_row(1).unwrap(), vec![4, 5, 6]);
+
+    let g = Grid::try_from_vec((1, 5), (1., 1.), vec![1, 2, 3, 4, 5]).unwrap();
+    assert_eq!(g.get((0, 4)).unwrap(), &5);
+
+    assert_eq!(
+        Grid::try_from_vec((2, 3), (1., 1.), vec![1, 2, 3, 4]).err(),
+        Some(GridErr::SizeMismatch {
+            expected: 6,
+            actual: 4
+        })
+    );
+    assert_eq!(
+        Grid::<i32>::try_from_vec((0, 3), (1., 1.), vec![]).err(),
+        Some(GridErr::InvalidSize { rows: 0, cols: 3 })
+    );
+    assert_eq!(
+        Grid::<i32>::try_from_vec((-1, -1), (1., 1.), vec![1]).err(),
+        Some(GridErr::InvalidSize { rows: -1, cols: -1 })
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_new_from_vector_size_mismatch() {
+    Grid::new_from_vector((2, 2), (1., 1.), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_grid_with_heap_values() {
+    let mut g: Grid<Vec<&str>> = Grid::new((2, 3), (1., 1.), vec![]);
+    g.set((0, 0), vec!["sword", "shield"]);
+    assert_eq!(g.mov((0, 0), (1, 2)), Ok(vec![]));
+    assert_eq!(g.get((0, 0)).unwrap(), &Vec::<&str>::new());
+    assert_eq!(g.get((1, 2)).unwrap(), &vec!["sword", "shield"]);
+
+    g.get_mut((1, 2)).unwrap().push("potion");
+    assert_eq!(g.take((1, 2)), Ok(vec!["sword", "shield", "potion"]));
+    assert_eq!(
+        g.take((2, 0)),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(2, 0)
+        })
+    );
+
+    let mut g = Grid::new((2, 2), (1., 1.), String::from("empty"));
+    assert_eq!(g.replace((1, 0), String::from("gold")).unwrap(), "empty");
+    assert_eq!(g.get_col(0).unwrap(), vec!["empty", "gold"]);
+    assert_eq!(
+        g.mov_to((1, 0), MoveDirection::Right),
+        Ok(String::from("empty"))
+    );
+    assert_eq!(g.get_row(1).unwrap(), vec!["empty", "gold"]);
+}
+
+#[test]
+fn test_grid_with_boxed_values() {
+    trait Entity {
+        fn name(&self) -> String;
+    }
+
+    struct Empty;
+    struct Orc(i32);
+
+    impl Entity for Empty {
+        fn name(&self) -> String {
+            String::from("empty")
+        }
+    }
+
+    impl Entity for Orc {
+        fn name(&self) -> String {
+            format!("orc {}", self.0)
+        }
+    }
+
+    let mut g: Grid<Box<dyn Entity>> = Grid::new_with((3, 2), (1., 1.), || Box::new(Empty) as _);
+    assert!(g.set((2, 1), Box::new(Orc(1))).is_ok());
+
+    let prev = g.replace((2, 1), Box::new(Orc(2))).unwrap();
+    assert_eq!(prev.name(), "orc 1");
+    assert_eq!(g[(2, 1)].name(), "orc 2");
+
+    let sub_grid: Grid<Box<dyn Entity>> =
+        Grid::new_with((1, 2), (1., 1.), || Box::new(Orc(3)) as _);
+    assert!(g.stamp_subgrid((0, 0), sub_grid).is_ok());
+
+    let names = g.into_iter().map(|e| e.name()).collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        ["orc 3", "orc 3", "empty", "empty", "empty", "orc 2"]
+    );
+
+    assert_eq!(
+        Grid::<Box<dyn Entity>>::try_new_with((0, 2), (1., 1.), || Box::new(Empty) as _).err(),
+        Some(GridErr::InvalidSize { rows: 0, cols: 2 })
+    );
+}
+
+#[test]
+fn test_empty_value_and_captures() {
+    let mut g = Grid::new_from_vector((2, 2), (1., 1.), vec!['K', '.', '.', 'q']);
+    assert_eq!(g.empty_value(), &'K');
+    g.set_empty_value('.');
+    assert_eq!(g.empty_value(), &'.');
+
+    assert_eq!(g.mov((0, 0), (1, 1)), Ok('q'));
+    assert_eq!(g.get_flatten_grid(), vec!['.', '.', '.', 'K']);
+    assert_eq!(g.mov_to((1, 1), MoveDirection::Up), Ok('.'));
+    assert_eq!(g.mov_leaving((0, 1), (0, 0), '*'), Ok('.'));
+    assert_eq!(g.get_flatten_grid(), vec!['K', '*', '.', '.']);
+
+    // Moving to itself keeps the value and returns what was left behind
+    assert_eq!(g.mov_leaving((0, 0), (0, 0), '*'), Ok('*'));
+    assert_eq!(g.get((0, 0)), Ok(&'K'));
+
+    assert_eq!(
+        g.mov_leaving((0, 0), (0, 2), '*'),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(0, 2)
+        })
+    );
+    assert_eq!(g.get_flatten_grid(), vec!['K', '*', '.', '.']);
+}
@@ -0,0 +1,172 @@
+use std::collections::HashSet;
+
+use crate::{Grid, GridErr, Pos};
+
+impl<T> Grid<T> {
+    /// Swaps the values of two positions, nothing is lost
+    ///
+    /// If any of the positions is out of the grid it return an error of GridErr::OutOfGrid
+    ///
+    /// ```.rust
+    /// let mut grid = das_grid::Grid::new_from_vector((1, 3), (1., 1.), vec!['a', 'b', 'c']);
+    /// assert_eq!(grid.swap((0, 0), (0, 2)), Ok(()));
+    /// assert_eq!(grid.get_flatten_grid(), vec!['c', 'b', 'a']);
+    /// ```
+    pub fn swap(&mut self, a: impl Into<Pos>, b: impl Into<Pos>) -> Result<(), GridErr> {
+        let (a, b) = (a.into(), b.into());
+        self.check_grid_bounds(a)?;
+        self.check_grid_bounds(b)?;
+
+        let (a, b) = (self.cell_index(a), self.cell_index(b));
+        self.cells.swap(a, b);
+        Ok(())
+    }
+
+    /// Shifts the values of the row by n cols, to the right when n is positive
+    /// and to the left when it is negative
+    ///
+    /// With wrap the values that leave one side come back on the other side,
+    /// without wrap they are dropped and the vacated cells get the empty value
+    ///
+    /// If the row is out of the grid it return an error of GridErr::OutOfGrid
+    ///
+    /// ```.rust
+    /// let mut grid = das_grid::Grid::new((1, 4), (1., 1.), 0);
+    /// for col in 0..4 {
+    ///     grid.set((0, col), col + 1).unwrap();
+    /// }
+    /// grid.shift_row(0, 1, true).unwrap();
+    /// assert_eq!(grid.get_row(0).unwrap(), vec![4, 1, 2, 3]);
+    /// grid.shift_row(0, -2, false).unwrap();
+    /// assert_eq!(grid.get_row(0).unwrap(), vec![2, 3, 0, 0]);
+    /// ```
+    pub fn shift_row(&mut self, row: i32, n: i32, wrap: bool) -> Result<(), GridErr>
+    where
+        T: Clone,
+    {
+        self.check_grid_bounds(Pos::new(row, 0))?;
+        let indices = (0..self.cols)
+            .map(|col| self.cell_index(Pos::new(row, col)))
+            .collect::<Vec<_>>();
+        self.shift_cells(&indices, n, wrap);
+        Ok(())
+    }
+
+    /// Shifts the values of the col by n rows, down when n is positive
+    /// and up when it is negative
+    ///
+    /// With wrap the values that leave one side come back on the other side,
+    /// without wrap they are dropped and the vacated cells get the empty value
+    ///
+    /// If the col is out of the grid it return an error of GridErr::OutOfGrid
+    ///
+    /// ```.rust
+    /// let mut grid = das_grid::Grid::new_from_vector((3, 2), (1., 1.), vec![1, 2, 3, 4, 5, 6]);
+    /// grid.shift_col(1, -1, true).unwrap();
+    /// assert_eq!(grid.get_col(1).unwrap(), vec![4, 6, 2]);
+    /// ```
+    pub fn shift_col(&mut self, col: i32, n: i32, wrap: bool) -> Result<(), GridErr>
+    where
+        T: Clone,
+    {
+        self.check_grid_bounds(Pos::new(0, col))?;
+        let indices = (0..self.rows)
+            .map(|row| self.cell_index(Pos::new(row, col)))
+            .collect::<Vec<_>>();
+        self.shift_cells(&indices, n, wrap);
+        Ok(())
+    }
+
+    /// Moves many values at once, every value leaves its source before any destiny is written,
+    /// so the moves can overlap, like a chain or a cycle
+    ///
+    /// The sources that aren't a destiny get the empty value
+    ///
+    /// If any position is out of the grid it return an error of GridErr::OutOfGrid,
+    /// and if a source or a destiny is repeated it return an error of GridErr::Collision,
+    /// in both cases nothing is moved
+    ///
+    /// ```.rust
+    /// let mut grid = das_grid::Grid::new_from_vector((1, 4), (1., 1.), vec![0, 1, 2, 3]);
+    /// // Rotates the first three cells
+    /// grid.move_many(&[((0, 0), (0, 1)), ((0, 1), (0, 2)), ((0, 2), (0, 0))]).unwrap();
+    /// assert_eq!(grid.get_flatten_grid(), vec![2, 0, 1, 3]);
+    /// // Moves every value one cell to the left, the last cell gets the empty value
+    /// grid.move_many(&[((0, 1), (0, 0)), ((0, 2), (0, 1)), ((0, 3), (0, 2))]).unwrap();
+    /// assert_eq!(grid.get_flatten_grid(), vec![0, 1, 3, 0]);
+    /// ```
+    pub fn move_many<P>(&mut self, moves: &[(P, P)]) -> Result<(), GridErr>
+    where
+        T: Clone,
+        P: Into<Pos> + Copy,
+    {
+        // The positions wrapped by the topology, so two positions of the same cell collide
+        let moves = moves
+            .iter()
+            .map(|(src, dst)| Ok((self.wrap(*src)?, self.wrap(*dst)?)))
+            .collect::<Result<Vec<(Pos, Pos)>, GridErr>>()?;
+
+        let (mut srcs, mut dsts) = (HashSet::new(), HashSet::new());
+        let mut repeated = vec![];
+        for (src, dst) in &moves {
+            if !srcs.insert(*src) {
+                repeated.push(*src);
+            }
+            if !dsts.insert(*dst) {
+                repeated.push(*dst);
+            }
+        }
+        if !repeated.is_empty() {
+            return Err(GridErr::Collision {
+                positions: repeated,
+            });
+        }
+
+        let values = moves
+            .iter()
+            .map(|(src, _)| {
+                let idx = self.cell_index(*src);
+                std::mem::replace(&mut self.cells[idx], self.initial_value.clone())
+            })
+            .collect::<Vec<_>>();
+
+        for ((_, dst), value) in moves.iter().zip(values) {
+            let idx = self.cell_index(*dst);
+            self.cells[idx] = value;
+        }
+
+        Ok(())
+    }
+
+    // Rotates the values of the cells on the indices by n, the ones that
+    // wrap around are replaced by the empty value when wrap is false
+    fn shift_cells(&mut self, indices: &[usize], n: i32, wrap: bool)
+    where
+        T: Clone,
+    {
+        let len = indices.len() as i32;
+        let k = n.rem_euclid(len) as usize;
+
+        // Rotating right by k is reversing all and then each part
+        let reverse = |cells: &mut Vec<T>, part: &[usize]| {
+            for i in 0..part.len() / 2 {
+                cells.swap(part[i], part[part.len() - 1 - i]);
+            }
+        };
+        reverse(&mut self.cells, indices);
+        reverse(&mut self.cells, &indices[..k]);
+        reverse(&mut self.cells, &indices[k..]);
+
+        if !wrap {
+            // The cells that received the wrapped values are the vacated ones
+            let vacated = if n >= 0 {
+                &indices[..n.min(len) as usize]
+            } else {
+                &indices[(len + n.max(-len)) as usize..]
+            };
+            for idx in vacated {
+                self.cells[*idx] = self.initial_value.clone();
+            }
+        }
+    }
+}
@@ -0,0 +1,128 @@
+#![allow(warnings, unused)]
+#[macro_use]
+use pretty_assertions::{assert_eq, assert_ne};
+
+use crate::{Grid, GridErr, Pos, Topology};
+
+#[test]
+fn test_swap() {
+    let mut g = Grid::new_from_vector((2, 2), (1., 1.), (1..=4).collect());
+    assert_eq!(g.swap((0, 0), (1, 1)), Ok(()));
+    assert_eq!(g.get_flatten_grid(), vec![4, 2, 3, 1]);
+    assert_eq!(g.swap((0, 1), (0, 1)), Ok(()));
+    assert_eq!(g.get_flatten_grid(), vec![4, 2, 3, 1]);
+    assert_eq!(
+        g.swap((0, 0), (2, 0)),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(2, 0)
+        })
+    );
+}
+
+#[test]
+fn test_shift_row() {
+    let mut g = Grid::new_from_vector((2, 4), (1., 1.), (1..=8).collect());
+    g.set_empty_value(0);
+    assert_eq!(g.shift_row(0, 1, true), Ok(()));
+    assert_eq!(g.get_row(0).unwrap(), vec![4, 1, 2, 3]);
+    assert_eq!(g.shift_row(0, -5, true), Ok(()));
+    assert_eq!(g.get_row(0).unwrap(), vec![1, 2, 3, 4]);
+    assert_eq!(g.shift_row(0, 4, true), Ok(()));
+    assert_eq!(g.get_row(0).unwrap(), vec![1, 2, 3, 4]);
+
+    assert_eq!(g.shift_row(1, 1, false), Ok(()));
+    assert_eq!(g.get_row(1).unwrap(), vec![0, 5, 6, 7]);
+    assert_eq!(g.shift_row(1, -3, false), Ok(()));
+    assert_eq!(g.get_row(1).unwrap(), vec![7, 0, 0, 0]);
+    assert_eq!(g.shift_row(0, 9, false), Ok(()));
+    assert_eq!(g.get_row(0).unwrap(), vec![0, 0, 0, 0]);
+    assert_eq!(g.get_row(1).unwrap(), vec![7, 0, 0, 0]);
+
+    assert_eq!(
+        g.shift_row(2, 1, true),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(2, 0)
+        })
+    );
+}
+
+#[test]
+fn test_shift_col() {
+    let mut g = Grid::new_from_vector((3, 2), (1., 1.), (1..=6).collect());
+    g.set_empty_value(0);
+    assert_eq!(g.shift_col(0, 1, true), Ok(()));
+    assert_eq!(g.get_col(0).unwrap(), vec![5, 1, 3]);
+    assert_eq!(g.shift_col(1, 2, false), Ok(()));
+    assert_eq!(g.get_col(1).unwrap(), vec![0, 0, 2]);
+    assert_eq!(g.shift_col(1, -1, false), Ok(()));
+    assert_eq!(g.get_col(1).unwrap(), vec![0, 2, 0]);
+    assert_eq!(
+        g.shift_col(-1, 1, false),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(0, -1)
+        })
+    );
+}
+
+#[test]
+fn test_move_many_overlapping() {
+    // A chain where every destiny is the next source
+    let mut g = Grid::new_from_vector((1, 4), (1., 1.), (1..=4).collect());
+    g.set_empty_value(0);
+    let chain = [((0, 2), (0, 3)), ((0, 1), (0, 2)), ((0, 0), (0, 1))];
+    assert_eq!(g.move_many(&chain), Ok(()));
+    assert_eq!(g.get_flatten_grid(), vec![0, 1, 2, 3]);
+
+    // A swap and a cycle of three together
+    let mut g = Grid::new_from_vector((1, 5), (1., 1.), (1..=5).collect());
+    g.set_empty_value(0);
+    let moves = [
+        ((0, 0), (0, 1)),
+        ((0, 1), (0, 0)),
+        ((0, 2), (0, 3)),
+        ((0, 3), (0, 4)),
+        ((0, 4), (0, 2)),
+    ];
+    assert_eq!(g.move_many(&moves), Ok(()));
+    assert_eq!(g.get_flatten_grid(), vec![2, 1, 5, 3, 4]);
+
+    // Moving to itself keeps the value
+    assert_eq!(g.move_many(&[(Pos::new(0, 0), Pos::new(0, 0))]), Ok(()));
+    assert_eq!(g.get((0, 0)), Ok(&2));
+}
+
+#[test]
+fn test_move_many_invalid() {
+    let mut g = Grid::new_from_vector((2, 2), (1., 1.), (1..=4).collect());
+    assert_eq!(
+        g.move_many(&[((0, 0), (1, 1)), ((0, 1), (1, 1)), ((0, 1), (1, 0))]),
+        Err(GridErr::Collision {
+            positions: vec![Pos::new(1, 1), Pos::new(0, 1)]
+        })
+    );
+    assert_eq!(
+        g.move_many(&[((0, 0), (1, 1)), ((1, 0), (1, 2))]),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(1, 2)
+        })
+    );
+    assert_eq!(g.get_flatten_grid(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn test_move_many_wrapped_collision() {
+    let mut g = Grid::new_from_vector((1, 3), (1., 1.), vec![1, 2, 3]);
+    g.set_topology(Topology::WrapX);
+    g.set_empty_value(0);
+    // The col 4 is the col 1 wrapped around
+    assert_eq!(
+        g.move_many(&[((0, 0), (0, 1)), ((0, 2), (0, 4))]),
+        Err(GridErr::Collision {
+            positions: vec![Pos::new(0, 1)]
+        })
+    );
+    assert_eq!(g.get_flatten_grid(), vec![1, 2, 3]);
+
+    g.move_many(&[((0, 0), (0, -1)), ((0, 2), (0, 4))]).unwrap();
+    assert_eq!(g.get_flatten_grid(), vec![0, 3, 1]);
+}
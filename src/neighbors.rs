@@ -0,0 +1,203 @@
+use std::slice;
+
+use crate::{Grid, GridErr, GridRead, Offset, Pos, Topology};
+
+/// Represents how the distance between two cells is measured
+///
+/// DistanceMetric::Manhattan counts only orthogonal steps, a radius of 1 are the 4 neighbors
+///
+/// DistanceMetric::Chebyshev counts diagonal steps as 1, a radius of 1 are the 8 neighbors
+///
+/// DistanceMetric::Euclidean is the straight line distance, good for circles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DistanceMetric {
+    Manhattan,
+    Chebyshev,
+    Euclidean,
+}
+
+impl DistanceMetric {
+    /// The distance between two positions
+    ///
+    /// ```.rust
+    /// use das_grid::{DistanceMetric, Pos};
+    ///
+    /// let (a, b) = (Pos::new(0, 0), Pos::new(3, 4));
+    /// assert_eq!(DistanceMetric::Manhattan.distance(a, b), 7.);
+    /// assert_eq!(DistanceMetric::Chebyshev.distance(a, b), 4.);
+    /// assert_eq!(DistanceMetric::Euclidean.distance(a, b), 5.);
+    /// ```
+    pub fn distance(&self, a: Pos, b: Pos) -> f32 {
+        let offset = b - a;
+        match self {
+            DistanceMetric::Manhattan => offset.manhattan() as f32,
+            DistanceMetric::Chebyshev => offset.chebyshev() as f32,
+            DistanceMetric::Euclidean => (offset.row as f32).hypot(offset.col as f32),
+        }
+    }
+
+    /// Checks if the offset is inside of the radius, without rounding errors
+    ///
+    /// The euclidean check is made on i64, so any radius up to `i32::MAX` is valid
+    pub fn within(&self, offset: Offset, radius: i32) -> bool {
+        match self {
+            DistanceMetric::Manhattan => offset.manhattan() <= radius,
+            DistanceMetric::Chebyshev => offset.chebyshev() <= radius,
+            DistanceMetric::Euclidean => {
+                let (row, col, radius) = (offset.row as i64, offset.col as i64, radius as i64);
+                row * row + col * col <= radius * radius
+            }
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Returns the orthogonal neighbors (von Neumann neighborhood) of the position
+    /// with their values, the cells out of the grid are skipped
+    ///
+    /// ```.rust
+    /// let grid = das_grid::Grid::new_from_vector((3, 3), (1., 1.), (1..=9).collect());
+    /// let values = grid.neighbors4((0, 0)).map(|(_, v)| *v).collect::<Vec<_>>();
+    /// assert_eq!(values, vec![2, 4]);
+    /// ```
+    pub fn neighbors4(&self, pos: impl Into<Pos>) -> impl Iterator<Item = (Pos, &T)> {
+        GridRead::neighbors4(self, pos)
+    }
+
+    /// Returns the orthogonal and diagonal neighbors (Moore neighborhood) of the position
+    /// with their values, the cells out of the grid are skipped
+    ///
+    /// ```.rust
+    /// let grid = das_grid::Grid::new_from_vector((3, 3), (1., 1.), (1..=9).collect());
+    /// let sum = grid.neighbors8((1, 1)).map(|(_, v)| *v).sum::<i32>();
+    /// assert_eq!(sum, 40);
+    /// ```
+    pub fn neighbors8(&self, pos: impl Into<Pos>) -> impl Iterator<Item = (Pos, &T)> {
+        GridRead::neighbors8(self, pos)
+    }
+
+    /// Returns every cell inside of the radius of the position, measured with the given metric,
+    /// the position itself and the cells out of the grid are skipped
+    ///
+    /// ```.rust
+    /// use das_grid::DistanceMetric;
+    ///
+    /// let grid = das_grid::Grid::new((9, 9), (1., 1.), 0);
+    /// assert_eq!(grid.neighbors_in_radius((4, 4), 2, DistanceMetric::Manhattan).count(), 12);
+    /// assert_eq!(grid.neighbors_in_radius((4, 4), 2, DistanceMetric::Chebyshev).count(), 24);
+    /// assert_eq!(grid.neighbors_in_radius((0, 0), 2, DistanceMetric::Chebyshev).count(), 8);
+    /// ```
+    pub fn neighbors_in_radius(
+        &self,
+        pos: impl Into<Pos>,
+        radius: i32,
+        metric: DistanceMetric,
+    ) -> impl Iterator<Item = (Pos, &T)> {
+        GridRead::neighbors_in_radius(self, pos, radius, metric)
+    }
+
+    /// Returns the cells at each offset of the stencil from the position,
+    /// the cells out of the grid are skipped
+    ///
+    /// ```.rust
+    /// use das_grid::Offset;
+    ///
+    /// let grid = das_grid::Grid::new((8, 8), (1., 1.), 0);
+    /// let knight = [
+    ///     Offset::new(-2, -1), Offset::new(-2, 1), Offset::new(-1, -2), Offset::new(-1, 2),
+    ///     Offset::new(1, -2), Offset::new(1, 2), Offset::new(2, -1), Offset::new(2, 1),
+    /// ];
+    /// assert_eq!(grid.neighbors_with((0, 0), &knight).count(), 2);
+    /// assert_eq!(grid.neighbors_with((4, 4), &knight).count(), 8);
+    /// ```
+    pub fn neighbors_with<'a>(
+        &'a self,
+        pos: impl Into<Pos>,
+        offsets: &'a [Offset],
+    ) -> impl Iterator<Item = (Pos, &'a T)> + 'a {
+        GridRead::neighbors_with(self, pos, offsets)
+    }
+}
+
+// The positions inside of the radius of the position, without the position itself
+pub(crate) fn radius(pos: Pos, radius: i32, metric: DistanceMetric) -> impl Iterator<Item = Pos> {
+    (-radius..=radius)
+        .flat_map(move |row| (-radius..=radius).map(move |col| Offset::new(row, col)))
+        .filter(move |offset| *offset != Offset::default() && metric.within(*offset, radius))
+        .map(move |offset| pos + offset)
+}
+
+// Pairs each position with its value, wrapping the positions with the topology
+// and skipping the ones out of the grid
+pub(crate) fn cells_at<'a, G, I>(
+    grid: &'a G,
+    positions: I,
+) -> impl Iterator<Item = (Pos, &'a G::Item)> + 'a
+where
+    G: GridRead + ?Sized,
+    I: IntoIterator<Item = Pos>,
+    I::IntoIter: 'a,
+{
+    positions.into_iter().filter_map(move |pos| {
+        let pos = grid.wrap(pos).ok()?;
+        grid.get(pos).ok().map(|value| (pos, value))
+    })
+}
+
+// The cells at each offset of a stencil from a position, wrapped with the topology
+// of the grid, the ones out of the grid are skipped
+pub(crate) struct Stencil<'a, G: ?Sized> {
+    grid: &'a G,
+    pos: Pos,
+    offsets: slice::Iter<'a, Offset>,
+    topology: Topology,
+}
+
+impl<'a, G> Stencil<'a, G>
+where
+    G: GridRead + ?Sized,
+{
+    pub(crate) fn new(grid: &'a G, pos: Pos, offsets: &'a [Offset]) -> Self {
+        Self {
+            grid,
+            pos,
+            offsets: offsets.iter(),
+            topology: Topology::Bounded,
+        }
+    }
+
+    // Wraps the positions with the topology before the grid does,
+    // so the stencil can wrap more axes than the grid itself
+    pub(crate) fn wrapping(self, topology: Topology) -> Self {
+        Self { topology, ..self }
+    }
+
+    // The position the offsets are taken from
+    pub(crate) fn pos(&self) -> Pos {
+        self.pos
+    }
+
+    fn cell(&self, offset: Offset) -> Result<(Pos, &'a G::Item), GridErr> {
+        let pos = self
+            .topology
+            .wrapped(self.pos + offset, self.grid.dimensions());
+        let pos = self.grid.wrap(pos)?;
+        self.grid.get(pos).map(|value| (pos, value))
+    }
+}
+
+impl<'a, G> Iterator for Stencil<'a, G>
+where
+    G: GridRead + ?Sized,
+{
+    type Item = (Pos, &'a G::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(offset) = self.offsets.next() {
+            if let Ok(cell) = self.cell(*offset) {
+                return Some(cell);
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,93 @@
+#![allow(warnings, unused)]
+#[macro_use]
+use pretty_assertions::{assert_eq, assert_ne};
+
+use crate::{DistanceMetric, Grid, Offset, Pos};
+
+#[test]
+fn test_neighbors4() {
+    let g = Grid::new_from_vector((4, 5), (1., 1.), (0..20).collect());
+
+    let mut values = g.neighbors4((1, 1)).map(|(_, v)| *v).collect::<Vec<_>>();
+    values.sort();
+    assert_eq!(values, vec![1, 5, 7, 11]);
+
+    let corner = g.neighbors4((3, 4)).collect::<Vec<_>>();
+    assert_eq!(corner, vec![(Pos::new(3, 3), &18), (Pos::new(2, 4), &14)]);
+
+    assert_eq!(g.neighbors4((-5, -5)).count(), 0);
+}
+
+#[test]
+fn test_neighbors8() {
+    let g = Grid::new_from_vector((4, 5), (1., 1.), (0..20).collect());
+
+    let mut values = g.neighbors8((1, 1)).map(|(_, v)| *v).collect::<Vec<_>>();
+    values.sort();
+    assert_eq!(values, vec![0, 1, 2, 5, 7, 10, 11, 12]);
+
+    assert_eq!(g.neighbors8((0, 0)).count(), 3);
+    assert_eq!(g.neighbors8((0, 2)).count(), 5);
+    assert!(g.neighbors8((2, 2)).all(|(pos, v)| g[pos] == *v));
+}
+
+#[test]
+fn test_neighbors_in_radius() {
+    let g = Grid::new((11, 11), (1., 1.), 0);
+    let center = Pos::new(5, 5);
+
+    for radius in 0..=5 {
+        let manhattan = g.neighbors_in_radius(center, radius, DistanceMetric::Manhattan);
+        assert_eq!(manhattan.count() as i32, 2 * radius * (radius + 1));
+
+        let chebyshev = g.neighbors_in_radius(center, radius, DistanceMetric::Chebyshev);
+        assert_eq!(chebyshev.count() as i32, (2 * radius + 1).pow(2) - 1);
+    }
+
+    let euclidean = g
+        .neighbors_in_radius(center, 2, DistanceMetric::Euclidean)
+        .map(|(pos, _)| pos - center)
+        .collect::<Vec<_>>();
+    assert_eq!(euclidean.len(), 12);
+    assert!(euclidean.contains(&Offset::new(1, 1)));
+    assert!(!euclidean.contains(&Offset::new(2, 2)));
+    assert!(!euclidean.contains(&Offset::new(0, 0)));
+
+    assert_eq!(
+        g.neighbors_in_radius((0, 0), 1, DistanceMetric::Manhattan)
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn test_neighbors_with_stencil() {
+    let g = Grid::new_from_vector((4, 5), (1., 1.), (0..20).collect());
+    let stencil = [Offset::new(0, 0), Offset::new(0, 2), Offset::new(2, 0)];
+
+    let cells = g.neighbors_with((1, 3), &stencil).collect::<Vec<_>>();
+    assert_eq!(cells, vec![(Pos::new(1, 3), &8), (Pos::new(3, 3), &18)]);
+}
+
+#[test]
+fn test_distance_metric() {
+    let (a, b) = (Pos::new(1, 1), Pos::new(4, -3));
+    assert_eq!(DistanceMetric::Manhattan.distance(a, b), 7.);
+    assert_eq!(DistanceMetric::Chebyshev.distance(a, b), 4.);
+    assert_eq!(DistanceMetric::Euclidean.distance(a, b), 5.);
+    assert!(DistanceMetric::Euclidean.within(Offset::new(3, 4), 5));
+    assert!(!DistanceMetric::Manhattan.within(Offset::new(3, 4), 5));
+}
+
+#[test]
+fn test_distance_metric_large_radius() {
+    let far = Offset::new(50_000, 50_000);
+    assert!(DistanceMetric::Euclidean.within(far, i32::MAX));
+    assert!(DistanceMetric::Euclidean.within(Offset::new(i32::MAX, 0), i32::MAX));
+    assert!(!DistanceMetric::Euclidean.within(far, 70_000));
+    assert!(DistanceMetric::Euclidean.within(far, 70_711));
+    assert_eq!(
+        DistanceMetric::Euclidean.distance(Pos::new(0, 0), Pos::new(60_000, 80_000)),
+        100_000.
+    );
+}
@@ -0,0 +1,437 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+};
+
+use crate::{Grid, GridErr, GridRead, MoveDirection, Pos};
+
+/// Represents which cells are reachable in a single step
+///
+/// Connectivity::Four moves only Up, Down, Left and Right
+///
+/// Connectivity::Eight moves also on the diagonals, when `cut_corners` is false
+/// a diagonal step is only allowed if both orthogonal cells beside it are passable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    Four,
+    Eight { cut_corners: bool },
+}
+
+impl Connectivity {
+    /// The directions of a single step
+    pub fn directions(&self) -> &'static [MoveDirection] {
+        match self {
+            Connectivity::Four => &MoveDirection::ORTHOGONAL,
+            Connectivity::Eight { .. } => &MoveDirection::ALL,
+        }
+    }
+}
+
+/// Represents the path found between two positions
+///
+/// The positions start on the origin and end on the goal, the cost is the sum
+/// of the cost of every cell entered on the way, so the origin isn't counted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    pub positions: Vec<Pos>,
+    pub cost: u32,
+}
+
+/// Represents the cells reachable from an origin within a number of movement points
+///
+/// Each cell holds the cheapest cost to reach it and the position it was reached from,
+/// so the path to any of them can be rebuilt
+pub struct MovementRange {
+    pub costs: Grid<Option<u32>>,
+    pub prev: Grid<Option<Pos>>,
+}
+
+impl MovementRange {
+    /// The cheapest cost to reach the position, None if it is out of range or out of the grid
+    pub fn cost(&self, pos: impl Into<Pos>) -> Option<u32> {
+        self.costs.get(pos).ok().copied().flatten()
+    }
+
+    /// Returns true if the position can be reached
+    pub fn contains(&self, pos: impl Into<Pos>) -> bool {
+        self.cost(pos).is_some()
+    }
+
+    /// The reachable positions with their costs, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (Pos, u32)> + '_ {
+        self.costs
+            .positions()
+            .zip(self.costs.cells.iter())
+            .filter_map(|(pos, cost)| cost.map(|c| (pos, c)))
+    }
+
+    /// The cheapest path from the origin to the position, None if it can't be reached
+    pub fn path_to(&self, dst: impl Into<Pos>) -> Option<Path> {
+        let dst = dst.into();
+        let cost = self.cost(dst)?;
+        Some(Path {
+            positions: rebuild_path(&self.prev, &self.prev.cells, self.prev.wrap(dst).ok()?),
+            cost,
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// Finds the path with the fewest steps between two positions using breadth first search
+    ///
+    /// The passable function tells which cells can be entered, the origin is never checked
+    ///
+    /// Returns None if the goal can't be reached or if any position is out of the grid
+    ///
+    /// ```.rust
+    /// use das_grid::{Connectivity, Grid};
+    ///
+    /// let grid = Grid::new_from_vector((3, 3), (1., 1.), vec![
+    ///     '.', '#', '.',
+    ///     '.', '#', '.',
+    ///     '.', '.', '.',
+    /// ]);
+    /// let path = grid.bfs_path((0, 0), (0, 2), Connectivity::Four, |c| *c != '#').unwrap();
+    /// assert_eq!(path.cost, 6);
+    /// assert_eq!(path.positions.len(), 7);
+    /// ```
+    pub fn bfs_path<F>(
+        &self,
+        src: impl Into<Pos>,
+        dst: impl Into<Pos>,
+        connectivity: Connectivity,
+        passable: F,
+    ) -> Option<Path>
+    where
+        F: Fn(&T) -> bool,
+    {
+        GridRead::bfs_path(self, src, dst, connectivity, passable)
+    }
+
+    /// Finds the cheapest path between two positions using Dijkstra
+    ///
+    /// The cost function returns the cost to enter a cell or None when it is blocked,
+    /// the origin is never checked
+    ///
+    /// Returns None if the goal can't be reached or if any position is out of the grid
+    ///
+    /// ```.rust
+    /// use das_grid::{Connectivity, Grid};
+    ///
+    /// // The swamp (9) on the straight line is more expensive than walking around it
+    /// let grid = Grid::new_from_vector((2, 3), (1., 1.), vec![
+    ///     1, 9, 1,
+    ///     1, 1, 1,
+    /// ]);
+    /// let path = grid.dijkstra_path((0, 0), (0, 2), Connectivity::Four, |c| Some(*c)).unwrap();
+    /// assert_eq!(path.cost, 4);
+    /// ```
+    pub fn dijkstra_path<F>(
+        &self,
+        src: impl Into<Pos>,
+        dst: impl Into<Pos>,
+        connectivity: Connectivity,
+        cost: F,
+    ) -> Option<Path>
+    where
+        F: Fn(&T) -> Option<u32>,
+    {
+        GridRead::dijkstra_path(self, src, dst, connectivity, cost)
+    }
+
+    /// Finds the cheapest path between two positions using A*
+    ///
+    /// The cost function returns the cost to enter a cell or None when it is blocked,
+    /// the origin is never checked
+    ///
+    /// The heuristic receives the current position and the goal and must never
+    /// overestimate the real cost, otherwise the path found may not be the cheapest
+    ///
+    /// Returns None if the goal can't be reached or if any position is out of the grid
+    ///
+    /// ```.rust
+    /// use das_grid::{Connectivity, Grid, Pos};
+    ///
+    /// let grid = Grid::new((10, 10), (1., 1.), 1);
+    /// let path = grid
+    ///     .astar_path((0, 0), (9, 9), Connectivity::Four, |c| Some(*c), |a: Pos, b: Pos| {
+    ///         a.manhattan(b) as u32
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(path.cost, 18);
+    /// ```
+    pub fn astar_path<F, H>(
+        &self,
+        src: impl Into<Pos>,
+        dst: impl Into<Pos>,
+        connectivity: Connectivity,
+        cost: F,
+        heuristic: H,
+    ) -> Option<Path>
+    where
+        F: Fn(&T) -> Option<u32>,
+        H: Fn(Pos, Pos) -> u32,
+    {
+        GridRead::astar_path(self, src, dst, connectivity, cost, heuristic)
+    }
+
+    /// Finds every cell reachable from the origin spending at most the given points
+    ///
+    /// The cost function returns the cost to enter a cell or None when it is blocked,
+    /// the origin is always reachable with cost 0
+    ///
+    /// Returns an error of GridErr::OutOfGrid if the origin is out of the grid
+    ///
+    /// ```.rust
+    /// use das_grid::{Connectivity, Grid};
+    ///
+    /// // The forest (2) costs twice as the plains (1)
+    /// let grid = Grid::new_from_vector((3, 3), (1., 1.), vec![
+    ///     1, 2, 1,
+    ///     1, 1, 1,
+    ///     2, 2, 1,
+    /// ]);
+    /// let range = grid.movement_range((0, 0), 2, Connectivity::Four, |c| Some(*c)).unwrap();
+    /// assert_eq!(range.cost((0, 1)), Some(2));
+    /// assert_eq!(range.cost((1, 1)), Some(2));
+    /// assert_eq!(range.cost((2, 0)), None);
+    /// assert_eq!(range.path_to((1, 1)).unwrap().positions.len(), 3);
+    /// ```
+    pub fn movement_range<F>(
+        &self,
+        src: impl Into<Pos>,
+        points: u32,
+        connectivity: Connectivity,
+        cost: F,
+    ) -> Result<MovementRange, GridErr>
+    where
+        F: Fn(&T) -> Option<u32>,
+    {
+        GridRead::movement_range(self, src, points, connectivity, cost)
+    }
+}
+
+// The best cost and the predecessor of every cell, row by row
+type Explored = (Vec<Option<u32>>, Vec<Option<Pos>>);
+
+// The cheapest cost and predecessor of every cell reachable from the origin within the points
+pub(crate) fn movement_range<G, F>(
+    grid: &G,
+    src: Pos,
+    points: u32,
+    connectivity: Connectivity,
+    cost: &F,
+) -> Result<Explored, GridErr>
+where
+    G: GridRead + ?Sized,
+    F: Fn(&G::Item) -> Option<u32>,
+{
+    let src = grid.wrap(src)?;
+    Ok(explore(
+        grid,
+        src,
+        None,
+        points,
+        connectivity,
+        cost,
+        &|_, _| 0,
+    ))
+}
+
+// Breadth first search from the origin to the goal, both already wrapped inside of the grid
+pub(crate) fn bfs<G, F>(
+    grid: &G,
+    src: Pos,
+    dst: Pos,
+    connectivity: Connectivity,
+    passable: &F,
+) -> Option<Path>
+where
+    G: GridRead + ?Sized,
+    F: Fn(&G::Item) -> bool,
+{
+    // Staying on the origin never enters a cell, even when the origin is blocked
+    if src == dst {
+        return Some(Path {
+            positions: vec![src],
+            cost: 0,
+        });
+    }
+
+    if !passable(grid.get(dst).ok()?) {
+        return None;
+    }
+
+    let mut prev: Vec<Option<Pos>> = vec![None; area(grid)];
+    let mut visited = vec![false; area(grid)];
+    let mut queue = VecDeque::from([src]);
+    visited[index(grid, src)] = true;
+
+    while let Some(pos) = queue.pop_front() {
+        if pos == dst {
+            let positions = rebuild_path(grid, &prev, dst);
+            let cost = positions.len() as u32 - 1;
+            return Some(Path { positions, cost });
+        }
+
+        for next in steps(grid, pos, connectivity, &|v| passable(v)) {
+            let idx = index(grid, next);
+            if !visited[idx] {
+                visited[idx] = true;
+                prev[idx] = Some(pos);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+// A* from the origin to the goal, both already wrapped inside of the grid
+pub(crate) fn astar<G, F, H>(
+    grid: &G,
+    src: Pos,
+    dst: Pos,
+    connectivity: Connectivity,
+    cost: &F,
+    heuristic: &H,
+) -> Option<Path>
+where
+    G: GridRead + ?Sized,
+    F: Fn(&G::Item) -> Option<u32>,
+    H: Fn(Pos, Pos) -> u32,
+{
+    // Staying on the origin never enters a cell, even when the origin is blocked
+    if src == dst {
+        return Some(Path {
+            positions: vec![src],
+            cost: 0,
+        });
+    }
+
+    cost(grid.get(dst).ok()?)?;
+
+    let (best, prev) = explore(
+        grid,
+        src,
+        Some(dst),
+        u32::MAX,
+        connectivity,
+        cost,
+        heuristic,
+    );
+    let spent = best[index(grid, dst)]?;
+    Some(Path {
+        positions: rebuild_path(grid, &prev, dst),
+        cost: spent,
+    })
+}
+
+// Dijkstra (or A* with a heuristic) from the origin, stops when the goal is reached
+// and never spends more than the budget, returns the best cost and predecessor of each cell
+fn explore<G, F, H>(
+    grid: &G,
+    src: Pos,
+    dst: Option<Pos>,
+    budget: u32,
+    connectivity: Connectivity,
+    cost: &F,
+    heuristic: &H,
+) -> Explored
+where
+    G: GridRead + ?Sized,
+    F: Fn(&G::Item) -> Option<u32>,
+    H: Fn(Pos, Pos) -> u32,
+{
+    let mut prev: Vec<Option<Pos>> = vec![None; area(grid)];
+    let mut best: Vec<Option<u32>> = vec![None; area(grid)];
+    let mut open = BinaryHeap::new();
+    let goal = dst.unwrap_or(src);
+    best[index(grid, src)] = Some(0);
+    open.push(Reverse((heuristic(src, goal), 0, src)));
+
+    while let Some(Reverse((_, spent, pos))) = open.pop() {
+        if Some(pos) == dst {
+            break;
+        }
+
+        // Skip the stale entries of cells already reached cheaper
+        if best[index(grid, pos)].is_some_and(|b| b < spent) {
+            continue;
+        }
+
+        for next in steps(grid, pos, connectivity, &|v| cost(v).is_some()) {
+            let idx = index(grid, next);
+            let Some(step) = grid.get(next).ok().and_then(cost) else {
+                continue;
+            };
+            let next_spent = spent.saturating_add(step);
+            if next_spent <= budget && best[idx].map_or(true, |b| next_spent < b) {
+                best[idx] = Some(next_spent);
+                prev[idx] = Some(pos);
+                open.push(Reverse((
+                    next_spent.saturating_add(heuristic(next, goal)),
+                    next_spent,
+                    next,
+                )));
+            }
+        }
+    }
+
+    (best, prev)
+}
+
+// The positions reachable in one step from the given position
+pub(crate) fn steps<G>(
+    grid: &G,
+    pos: Pos,
+    connectivity: Connectivity,
+    passable: &dyn Fn(&G::Item) -> bool,
+) -> Vec<Pos>
+where
+    G: GridRead + ?Sized,
+{
+    let is_passable = |p: Pos| grid.get(p).map(passable).unwrap_or(false);
+
+    connectivity
+        .directions()
+        .iter()
+        .filter(|direction| match connectivity {
+            Connectivity::Eight { cut_corners: false } if direction.is_diagonal() => {
+                let offset = direction.offset();
+                is_passable(pos + (offset.row, 0).into())
+                    && is_passable(pos + (0, offset.col).into())
+            }
+            _ => true,
+        })
+        .filter_map(|direction| grid.wrap(pos + direction.offset()).ok())
+        .filter(|next| is_passable(*next))
+        .collect()
+}
+
+// Walks back the predecessors from the goal to the origin
+fn rebuild_path<G>(grid: &G, prev: &[Option<Pos>], dst: Pos) -> Vec<Pos>
+where
+    G: GridRead + ?Sized,
+{
+    let mut positions = vec![dst];
+    let mut pos = dst;
+    while let Some(p) = prev[index(grid, pos)] {
+        positions.push(p);
+        pos = p;
+    }
+    positions.reverse();
+    positions
+}
+
+// The amount of cells of the grid
+pub(crate) fn area<G: GridRead + ?Sized>(grid: &G) -> usize {
+    let (rows, cols) = grid.dimensions();
+    (rows * cols) as usize
+}
+
+// Flatten index of a position already wrapped inside of the grid, row by row
+pub(crate) fn index<G: GridRead + ?Sized>(grid: &G, pos: Pos) -> usize {
+    let (_, cols) = grid.dimensions();
+    (pos.row * cols + pos.col) as usize
+}
@@ -0,0 +1,282 @@
+#![allow(warnings, unused)]
+#[macro_use]
+use pretty_assertions::{assert_eq, assert_ne};
+
+use crate::{Connectivity, Grid, MovementRange, Path, Pos};
+
+fn is_floor(c: &char) -> bool {
+    *c != '#'
+}
+
+fn is_adjacent(path: &Path) -> bool {
+    path.positions.windows(2).all(|w| w[0].chebyshev(w[1]) == 1)
+}
+
+#[test]
+fn test_bfs_path() {
+    let g = Grid::new_from_vector(
+        (4, 5),
+        (1., 1.),
+        "\
+        .....\
+        .###.\
+        ...#.\
+        .#...\
+        "
+        .chars()
+        .collect(),
+    );
+
+    let path = g
+        .bfs_path((3, 0), (3, 4), Connectivity::Four, is_floor)
+        .unwrap();
+    assert_eq!(path.cost, 6);
+    assert_eq!(path.positions.first(), Some(&Pos::new(3, 0)));
+    assert_eq!(path.positions.last(), Some(&Pos::new(3, 4)));
+    assert!(path.positions.iter().all(|p| is_floor(&g[*p])));
+    assert!(is_adjacent(&path));
+
+    let same = g
+        .bfs_path((0, 0), (0, 0), Connectivity::Four, is_floor)
+        .unwrap();
+    assert_eq!(same.positions, vec![Pos::new(0, 0)]);
+    assert_eq!(same.cost, 0);
+}
+
+#[test]
+fn test_path_not_found() {
+    let g = Grid::new_from_vector(
+        (4, 5),
+        (1., 1.),
+        "\
+        .....\
+        .###.\
+        ...#.\
+        .#...\
+        "
+        .chars()
+        .collect(),
+    );
+
+    assert_eq!(
+        g.bfs_path((0, 0), (1, 1), Connectivity::Four, is_floor),
+        None
+    );
+    assert_eq!(
+        g.bfs_path((0, 0), (9, 9), Connectivity::Four, is_floor),
+        None
+    );
+    assert_eq!(
+        g.bfs_path((-1, 0), (0, 0), Connectivity::Four, is_floor),
+        None
+    );
+
+    let walled = Grid::new_from_vector((1, 3), (1., 1.), vec![1, 0, 1]);
+    let cost = |v: &i32| if *v == 0 { None } else { Some(1) };
+    assert_eq!(
+        walled.dijkstra_path((0, 0), (0, 2), Connectivity::Four, cost),
+        None
+    );
+}
+
+#[test]
+fn test_corner_cutting() {
+    let g = Grid::new_from_vector(
+        (4, 5),
+        (1., 1.),
+        "\
+        .....\
+        .###.\
+        ...#.\
+        .#...\
+        "
+        .chars()
+        .collect(),
+    );
+    let cut = Connectivity::Eight { cut_corners: true };
+    let no_cut = Connectivity::Eight { cut_corners: false };
+
+    // The diagonal step from (2, 2) to (3, 3) passes beside the wall on (2, 3)
+    let path = g.bfs_path((2, 2), (3, 3), cut, is_floor).unwrap();
+    assert_eq!(path.positions, vec![Pos::new(2, 2), Pos::new(3, 3)]);
+
+    let path = g.bfs_path((2, 2), (3, 3), no_cut, is_floor).unwrap();
+    assert_eq!(
+        path.positions,
+        vec![Pos::new(2, 2), Pos::new(3, 2), Pos::new(3, 3)]
+    );
+
+    let squeeze = Grid::new_from_vector((2, 2), (1., 1.), vec!['.', '#', '#', '.']);
+    assert_eq!(
+        squeeze
+            .bfs_path((0, 0), (1, 1), cut, is_floor)
+            .unwrap()
+            .cost,
+        1
+    );
+    assert_eq!(squeeze.bfs_path((0, 0), (1, 1), no_cut, is_floor), None);
+    assert_eq!(
+        squeeze.bfs_path((0, 0), (1, 1), Connectivity::Four, is_floor),
+        None
+    );
+}
+
+#[test]
+fn test_dijkstra_and_astar_path() {
+    let g = Grid::new_from_vector(
+        (3, 4),
+        (1., 1.),
+        vec![
+            1, 5, 5, 1, //
+            1, 5, 5, 1, //
+            1, 1, 1, 1, //
+        ],
+    );
+    let cost = |v: &i32| Some(*v as u32);
+
+    let dijkstra = g
+        .dijkstra_path((0, 0), (0, 3), Connectivity::Four, cost)
+        .unwrap();
+    assert_eq!(dijkstra.cost, 7);
+    assert_eq!(dijkstra.positions.len(), 8);
+
+    let astar = g
+        .astar_path(
+            (0, 0),
+            (0, 3),
+            Connectivity::Four,
+            cost,
+            |a: Pos, b: Pos| a.manhattan(b) as u32,
+        )
+        .unwrap();
+    assert_eq!(astar.cost, dijkstra.cost);
+    assert!(is_adjacent(&astar));
+
+    let diagonal = g
+        .astar_path(
+            (0, 0),
+            (2, 3),
+            Connectivity::Eight { cut_corners: true },
+            cost,
+            |a: Pos, b: Pos| a.chebyshev(b) as u32,
+        )
+        .unwrap();
+    assert_eq!(diagonal.cost, 4);
+}
+
+#[test]
+fn test_movement_range() {
+    let g = Grid::new_from_vector(
+        (4, 5),
+        (1., 1.),
+        "\
+        .....\
+        .###.\
+        ...#.\
+        .#...\
+        "
+        .chars()
+        .collect(),
+    );
+    let cost = |c: &char| if is_floor(c) { Some(1) } else { None };
+
+    let range = g
+        .movement_range((0, 0), 3, Connectivity::Four, cost)
+        .unwrap();
+    let reached = range.positions().collect::<Vec<_>>();
+    assert_eq!(
+        reached,
+        vec![
+            (Pos::new(0, 0), 0),
+            (Pos::new(0, 1), 1),
+            (Pos::new(0, 2), 2),
+            (Pos::new(0, 3), 3),
+            (Pos::new(1, 0), 1),
+            (Pos::new(2, 0), 2),
+            (Pos::new(2, 1), 3),
+            (Pos::new(3, 0), 3),
+        ]
+    );
+    assert!(!range.contains((1, 1)));
+    assert!(!range.contains((0, 4)));
+    assert_eq!(range.cost((-1, 0)), None);
+
+    let path = range.path_to((2, 1)).unwrap();
+    assert_eq!(path.cost, 3);
+    assert_eq!(
+        path.positions,
+        vec![
+            Pos::new(0, 0),
+            Pos::new(1, 0),
+            Pos::new(2, 0),
+            Pos::new(2, 1)
+        ]
+    );
+    assert_eq!(range.path_to((3, 4)), None);
+
+    assert!(g
+        .movement_range((9, 9), 3, Connectivity::Four, cost)
+        .is_err());
+}
+
+#[test]
+fn test_movement_range_terrain() {
+    let g = Grid::new_from_vector(
+        (3, 3),
+        (1., 1.),
+        vec![
+            1, 3, 1, //
+            1, 0, 1, //
+            1, 1, 1, //
+        ],
+    );
+    let cost = |v: &i32| if *v == 0 { None } else { Some(*v as u32) };
+
+    let range = g
+        .movement_range((0, 0), 4, Connectivity::Four, cost)
+        .unwrap();
+    assert_eq!(
+        range.costs.get_row(0).unwrap(),
+        vec![Some(0), Some(3), Some(4)]
+    );
+    assert_eq!(range.costs.get_row(1).unwrap(), vec![Some(1), None, None]);
+    assert_eq!(
+        range.costs.get_row(2).unwrap(),
+        vec![Some(2), Some(3), Some(4)]
+    );
+    assert_eq!(range.prev.get((2, 2)), Ok(&Some(Pos::new(2, 1))));
+
+    // The diagonal reaches more cells with the same points
+    let eight = Connectivity::Eight { cut_corners: true };
+    let range = g.movement_range((0, 0), 4, eight, cost).unwrap();
+    assert_eq!(range.cost((2, 2)), Some(3));
+    assert_eq!(range.cost((1, 2)), Some(3));
+}
+
+#[test]
+fn test_path_to_itself_on_blocked_cell() {
+    // A unit standing on a door, which can't be entered
+    let g = Grid::new_from_vector((1, 3), (1., 1.), vec!['.', 'D', '.']);
+    let stay = Path {
+        positions: vec![Pos::new(0, 1)],
+        cost: 0,
+    };
+    assert_eq!(
+        g.bfs_path((0, 1), (0, 1), Connectivity::Four, |c| *c == '.'),
+        Some(stay.clone())
+    );
+
+    let cost = |c: &char| if *c == 'D' { None } else { Some(1) };
+    assert_eq!(
+        g.dijkstra_path((0, 1), (0, 1), Connectivity::Four, cost),
+        Some(stay.clone())
+    );
+    assert_eq!(
+        g.astar_path((0, 1), (0, 1), Connectivity::Four, cost, |_, _| 0),
+        Some(stay)
+    );
+    assert_eq!(
+        g.bfs_path((0, 0), (0, 1), Connectivity::Four, |c| *c == '.'),
+        None
+    );
+}
@@ -0,0 +1,303 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{Grid, GridRead, MoveDirection, Offset, Pos, Transform};
+
+/// Represents a run of cells in a line, from the first to the last position
+///
+/// The direction is MoveDirection::Right, MoveDirection::Down, MoveDirection::DownRight
+/// or MoveDirection::DownLeft, the positions follow the direction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub positions: Vec<Pos>,
+    pub direction: MoveDirection,
+}
+
+impl Run {
+    /// The amount of cells in the run
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Returns true if the run has no cells
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+}
+
+/// Represents a place where a pattern was found
+///
+/// The position is the top left corner of the pattern after the transform,
+/// and the positions are the cells matched by the pattern, without the wildcards
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternMatch {
+    pub pos: Pos,
+    pub transform: Transform,
+    pub positions: Vec<Pos>,
+}
+
+// The directions scanned for runs, one for each line
+const RUN_DIRECTIONS: [MoveDirection; 4] = [
+    MoveDirection::Right,
+    MoveDirection::Down,
+    MoveDirection::DownRight,
+    MoveDirection::DownLeft,
+];
+
+impl<T> Grid<T> {
+    /// Finds the horizontal, vertical and diagonal runs of at least min_len cells
+    /// where each cell is equal to the previous one
+    ///
+    /// Only the longest run of each line is returned, a run of 4 isn't returned again as two runs of 3
+    ///
+    /// ```.rust
+    /// let grid = das_grid::Grid::new_from_vector(
+    ///     (3, 3),
+    ///     (1., 1.),
+    ///     vec!['x', 'o', '.', 'o', 'x', '.', 'o', 'o', 'x'],
+    /// );
+    /// let runs = grid.find_runs(3, |a, b| a == b && *a != '.');
+    /// assert_eq!(runs.len(), 1);
+    /// assert_eq!(runs[0].direction, das_grid::MoveDirection::DownRight);
+    /// ```
+    pub fn find_runs<F>(&self, min_len: usize, eq: F) -> Vec<Run>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        GridRead::find_runs(self, min_len, eq)
+    }
+
+    /// Finds the horizontal, vertical and diagonal runs of at least min_len cells
+    /// where every cell matches the predicate
+    ///
+    /// ```.rust
+    /// let grid = das_grid::Grid::new_from_vector((2, 3), (1., 1.), vec![1, 2, 3, 0, 0, 4]);
+    /// let runs = grid.find_runs_by(3, |v| *v != 0);
+    /// assert_eq!(runs.len(), 1);
+    /// assert_eq!(runs[0].positions, vec![(0, 0).into(), (0, 1).into(), (0, 2).into()]);
+    /// ```
+    pub fn find_runs_by<F>(&self, min_len: usize, pred: F) -> Vec<Run>
+    where
+        F: Fn(&T) -> bool,
+    {
+        GridRead::find_runs_by(self, min_len, pred)
+    }
+
+    /// Finds the runs of at least min_len equal cells, like `Grid::find_runs`,
+    /// and merges the runs that share a cell, like the T and L shapes of a match-3 game
+    ///
+    /// Each match has the positions of its cells once, sorted by row and col
+    ///
+    /// ```.rust
+    /// let grid = das_grid::Grid::new_from_vector(
+    ///     (3, 3),
+    ///     (1., 1.),
+    ///     vec![1, 1, 1, 0, 1, 0, 0, 1, 2],
+    /// );
+    /// let matches = grid.find_matches(3, |a, b| a == b && *a != 0);
+    /// assert_eq!(matches.len(), 1);
+    /// assert_eq!(matches[0].len(), 5);
+    /// ```
+    pub fn find_matches<F>(&self, min_len: usize, eq: F) -> Vec<Vec<Pos>>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        GridRead::find_matches(self, min_len, eq)
+    }
+
+    /// Finds every place where the pattern fits, a cell of the pattern with Some value
+    /// must be equal to the cell of the grid and None is a wildcard
+    ///
+    /// The pattern is tried with each of the transforms, use `Transform::ROTATIONS`
+    /// to find it rotated, or just `Transform::Identity`
+    ///
+    /// The matches with the same positions are returned once, with the first transform that found it,
+    /// and a pattern made only of wildcards matches nothing
+    ///
+    /// ```.rust
+    /// use das_grid::{Grid, Pos, Transform};
+    ///
+    /// let grid = Grid::new_from_vector((3, 3), (1., 1.), vec![1, 0, 0, 1, 0, 1, 1, 1, 1]);
+    /// // An L shape of 1s
+    /// let pattern = Grid::new_from_vector((2, 2), (1., 1.), vec![Some(1), None, Some(1), Some(1)]);
+    ///
+    /// let found = grid.find_pattern(&pattern, &[Transform::Identity]);
+    /// assert_eq!(found.len(), 1);
+    /// assert_eq!(found[0].pos, Pos::new(1, 0));
+    ///
+    /// let found = grid.find_pattern(&pattern, &Transform::ROTATIONS);
+    /// assert_eq!(found.len(), 2);
+    /// ```
+    pub fn find_pattern(
+        &self,
+        pattern: &Grid<Option<T>>,
+        transforms: &[Transform],
+    ) -> Vec<PatternMatch>
+    where
+        T: PartialEq,
+    {
+        GridRead::find_pattern(self, pattern, transforms)
+    }
+}
+
+// Scans every line for runs, a run starts on a cell that passes the start check
+// and grows while the next cell joins the current one, the runs stop on the edges
+// of the grid even when the topology wraps them
+pub(crate) fn scan_runs<G, S, J>(grid: &G, min_len: usize, starts: &S, joins: &J) -> Vec<Run>
+where
+    G: GridRead + ?Sized,
+    S: Fn(&G::Item) -> bool,
+    J: Fn(&G::Item, &G::Item) -> bool,
+{
+    let continues = |from: &G::Item, to: &G::Item| starts(from) && joins(from, to);
+
+    let mut runs = vec![];
+    for direction in RUN_DIRECTIONS {
+        let offset = direction.offset();
+        for (pos, value) in grid.iter() {
+            if !starts(value) {
+                continue;
+            }
+            // The cell is inside a run that started before
+            if let Some(prev) = bounded(grid, pos - offset) {
+                if continues(prev, value) {
+                    continue;
+                }
+            }
+
+            let mut positions = vec![pos];
+            let mut current = value;
+            while let Some(next) = bounded(grid, pos + offset * positions.len() as i32) {
+                if !joins(current, next) {
+                    break;
+                }
+                positions.push(pos + offset * positions.len() as i32);
+                current = next;
+            }
+
+            if positions.len() >= min_len.max(1) {
+                runs.push(Run {
+                    positions,
+                    direction,
+                });
+            }
+        }
+    }
+
+    runs
+}
+
+// Merges the runs that share a cell, each match sorted by row and col
+pub(crate) fn merge_runs(runs: Vec<Run>) -> Vec<Vec<Pos>> {
+    let mut runs_on: HashMap<Pos, Vec<usize>> = HashMap::new();
+    for (idx, run) in runs.iter().enumerate() {
+        for pos in &run.positions {
+            runs_on.entry(*pos).or_default().push(idx);
+        }
+    }
+
+    // Groups the runs connected by a shared cell
+    let mut grouped = vec![false; runs.len()];
+    let mut matches = vec![];
+    for first in 0..runs.len() {
+        if grouped[first] {
+            continue;
+        }
+        grouped[first] = true;
+
+        let mut positions = HashSet::new();
+        let mut pending = vec![first];
+        while let Some(idx) = pending.pop() {
+            for pos in &runs[idx].positions {
+                positions.insert(*pos);
+                for other in &runs_on[pos] {
+                    if !grouped[*other] {
+                        grouped[*other] = true;
+                        pending.push(*other);
+                    }
+                }
+            }
+        }
+
+        let mut positions = positions.into_iter().collect::<Vec<_>>();
+        positions.sort();
+        matches.push(positions);
+    }
+
+    matches.sort();
+    matches
+}
+
+// Finds the places where the pattern fits with each of the transforms
+pub(crate) fn find_pattern<G>(
+    grid: &G,
+    pattern: &Grid<Option<G::Item>>,
+    transforms: &[Transform],
+) -> Vec<PatternMatch>
+where
+    G: GridRead + ?Sized,
+    G::Item: PartialEq,
+{
+    let frame_size = (pattern.rows, pattern.cols);
+    let (grid_rows, grid_cols) = grid.dimensions();
+    let mut seen = HashSet::new();
+    let mut found = vec![];
+
+    for transform in transforms {
+        let (rows, cols) = transform.frame_size(frame_size);
+        if rows > grid_rows || cols > grid_cols {
+            continue;
+        }
+
+        let cells = pattern
+            .positions()
+            .zip(pattern.cells.iter())
+            .filter_map(|(pos, value)| {
+                value
+                    .as_ref()
+                    .map(|v| (Offset::from(transform.apply(pos, frame_size)), v))
+            })
+            .collect::<Vec<_>>();
+        if cells.is_empty() {
+            continue;
+        }
+
+        for row in 0..=grid_rows - rows {
+            for col in 0..=grid_cols - cols {
+                let pos = Pos::new(row, col);
+                let fits = cells
+                    .iter()
+                    .all(|(offset, v)| bounded(grid, pos + *offset) == Some(*v));
+                if !fits {
+                    continue;
+                }
+
+                let mut positions = cells
+                    .iter()
+                    .map(|(offset, _)| pos + *offset)
+                    .collect::<Vec<_>>();
+                positions.sort();
+                if seen.insert(positions.clone()) {
+                    found.push(PatternMatch {
+                        pos,
+                        transform: *transform,
+                        positions,
+                    });
+                }
+            }
+        }
+    }
+
+    found
+}
+
+// The value of the position without wrapping the topology, None if it is out of the grid
+fn bounded<G>(grid: &G, pos: Pos) -> Option<&G::Item>
+where
+    G: GridRead + ?Sized,
+{
+    let (rows, cols) = grid.dimensions();
+    if pos.row < 0 || pos.row >= rows || pos.col < 0 || pos.col >= cols {
+        return None;
+    }
+    grid.get(pos).ok()
+}
@@ -0,0 +1,183 @@
+#![allow(warnings, unused)]
+#[macro_use]
+use pretty_assertions::{assert_eq, assert_ne};
+
+use crate::{Grid, MoveDirection, Pos, Run, Topology, Transform};
+
+fn same_piece(a: &char, b: &char) -> bool {
+    a == b && *a != '.'
+}
+
+fn positions(v: &[(i32, i32)]) -> Vec<Pos> {
+    v.iter().map(|p| Pos::from(*p)).collect()
+}
+
+#[test]
+fn test_find_runs_connect_four() {
+    let g = Grid::new_from_vector(
+        (4, 5),
+        (1., 1.),
+        "\
+        rrrr.\
+        yry..\
+        yyr..\
+        y.yr.\
+        "
+        .chars()
+        .collect(),
+    );
+
+    let runs = g.find_runs(3, same_piece);
+    assert_eq!(
+        runs,
+        vec![
+            Run {
+                positions: positions(&[(0, 0), (0, 1), (0, 2), (0, 3)]),
+                direction: MoveDirection::Right
+            },
+            Run {
+                positions: positions(&[(1, 0), (2, 0), (3, 0)]),
+                direction: MoveDirection::Down
+            },
+            Run {
+                positions: positions(&[(0, 0), (1, 1), (2, 2), (3, 3)]),
+                direction: MoveDirection::DownRight
+            },
+            Run {
+                positions: positions(&[(1, 0), (2, 1), (3, 2)]),
+                direction: MoveDirection::DownRight
+            },
+            Run {
+                positions: positions(&[(1, 2), (2, 1), (3, 0)]),
+                direction: MoveDirection::DownLeft
+            },
+        ]
+    );
+
+    // Only the longest run of the line, and no runs under the length
+    assert_eq!(g.find_runs(4, same_piece).len(), 2);
+    assert_eq!(g.find_runs(5, same_piece), vec![]);
+}
+
+#[test]
+fn test_find_runs_by() {
+    let g = Grid::new_from_vector(
+        (3, 4),
+        (1., 1.),
+        "\
+        ab.c\
+        .de.\
+        fghi\
+        "
+        .chars()
+        .collect(),
+    );
+    let runs = g.find_runs_by(3, |c| *c != '.');
+    assert_eq!(runs.len(), 5);
+    assert_eq!(
+        runs[0].positions,
+        positions(&[(2, 0), (2, 1), (2, 2), (2, 3)])
+    );
+    assert_eq!(runs[0].len(), 4);
+    assert_eq!(runs[1].positions, positions(&[(0, 1), (1, 1), (2, 1)]));
+    assert_eq!(runs[4].positions, positions(&[(0, 3), (1, 2), (2, 1)]));
+    assert_eq!(runs[4].direction, MoveDirection::DownLeft);
+}
+
+#[test]
+fn test_find_matches_intersecting() {
+    let g = Grid::new_from_vector(
+        (4, 4),
+        (1., 1.),
+        "\
+        aaab\
+        .a.b\
+        .a.b\
+        cc.b\
+        "
+        .chars()
+        .collect(),
+    );
+    let matches = g.find_matches(3, same_piece);
+    assert_eq!(
+        matches,
+        vec![
+            positions(&[(0, 0), (0, 1), (0, 2), (1, 1), (2, 1)]),
+            positions(&[(0, 3), (1, 3), (2, 3), (3, 3)]),
+        ]
+    );
+    assert_eq!(g.find_matches(5, same_piece), Vec::<Vec<Pos>>::new());
+}
+
+#[test]
+fn test_find_pattern() {
+    let g = Grid::new_from_vector(
+        (3, 4),
+        (1., 1.),
+        "\
+        xxx.\
+        .x..\
+        xxx.\
+        "
+        .chars()
+        .collect(),
+    );
+    // A T shape
+    let t = Grid::new_from_vector(
+        (2, 3),
+        (1., 1.),
+        vec![Some('x'), Some('x'), Some('x'), None, Some('x'), None],
+    );
+
+    let found = g.find_pattern(&t, &[Transform::Identity]);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].pos, Pos::new(0, 0));
+    assert_eq!(found[0].transform, Transform::Identity);
+    assert_eq!(
+        found[0].positions,
+        positions(&[(0, 0), (0, 1), (0, 2), (1, 1)])
+    );
+
+    // Upside down, the T is the middle of the col 1 with the row 2
+    let found = g.find_pattern(&t, &Transform::ROTATIONS);
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[1].pos, Pos::new(1, 0));
+    assert_eq!(found[1].transform, Transform::Rotate180);
+    assert_eq!(
+        found[1].positions,
+        positions(&[(1, 1), (2, 0), (2, 1), (2, 2)])
+    );
+}
+
+#[test]
+fn test_find_pattern_symmetric_and_wildcards() {
+    let g = Grid::new_from_vector((1, 4), (1., 1.), "xxxx".chars().collect());
+    let line = Grid::new_from_vector((1, 3), (1., 1.), vec![Some('x'); 3]);
+    // The half turn of a line is the same line, found once for each place
+    let found = g.find_pattern(&line, &Transform::ROTATIONS);
+    assert_eq!(found.len(), 2);
+    assert!(found.iter().all(|m| m.transform == Transform::Identity));
+
+    let wildcards = Grid::new((1, 2), (1., 1.), None);
+    assert_eq!(g.find_pattern(&wildcards, &Transform::ROTATIONS), vec![]);
+
+    let too_big = Grid::new((2, 2), (1., 1.), Some('x'));
+    assert_eq!(g.find_pattern(&too_big, &[Transform::Identity]), vec![]);
+}
+
+#[test]
+fn test_find_runs_ignore_topology() {
+    let mut g = Grid::new_from_vector((1, 3), (1., 1.), "aba".chars().collect());
+    g.set_topology(Topology::WrapX);
+    assert_eq!(g.find_runs(2, same_piece), vec![]);
+
+    let mut g = Grid::new_from_vector((2, 3), (1., 1.), "aaa...".chars().collect());
+    g.set_topology(Topology::Torus);
+    assert_eq!(
+        g.find_runs(3, same_piece),
+        vec![Run {
+            positions: positions(&[(0, 0), (0, 1), (0, 2)]),
+            direction: MoveDirection::Right
+        }]
+    );
+}
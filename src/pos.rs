@@ -0,0 +1,262 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::MoveDirection;
+
+/// Represents the address of a cell inside of the grid
+///
+/// The position follows the same order of the grid size `(rows, cols)`,
+/// so a tuple `(row, col)` converts straight into a position
+///
+/// ```.rust
+/// use das_grid::Pos;
+///
+/// let pos = Pos::new(2, 3);
+/// assert_eq!(pos, Pos::from((2, 3)));
+/// assert_eq!(pos.right(), Pos::new(2, 4));
+/// assert_eq!(pos + das_grid::Offset::new(1, -1), Pos::new(3, 2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Pos {
+    pub row: i32,
+    pub col: i32,
+}
+
+/// Represents the difference between two positions of the grid
+///
+/// ```.rust
+/// use das_grid::{Offset, Pos};
+///
+/// let offset = Pos::new(4, 1) - Pos::new(1, 2);
+/// assert_eq!(offset, Offset::new(3, -1));
+/// assert_eq!(offset * 2, Offset::new(6, -2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Offset {
+    pub row: i32,
+    pub col: i32,
+}
+
+impl Pos {
+    /// Creates a position on the given row and col
+    pub const fn new(row: i32, col: i32) -> Self {
+        Self { row, col }
+    }
+
+    /// The position one row above
+    pub fn up(self) -> Pos {
+        self + MoveDirection::Up.offset()
+    }
+
+    /// The position one row below
+    pub fn down(self) -> Pos {
+        self + MoveDirection::Down.offset()
+    }
+
+    /// The position one col to the left
+    pub fn left(self) -> Pos {
+        self + MoveDirection::Left.offset()
+    }
+
+    /// The position one col to the right
+    pub fn right(self) -> Pos {
+        self + MoveDirection::Right.offset()
+    }
+
+    /// The position moved one step to the given direction
+    ///
+    /// ```.rust
+    /// use das_grid::{MoveDirection, Pos};
+    ///
+    /// assert_eq!(Pos::new(1, 1).step(MoveDirection::Up), Pos::new(0, 1));
+    /// ```
+    pub fn step(self, direction: MoveDirection) -> Pos {
+        self + direction.offset()
+    }
+
+    /// The four orthogonal neighbors in the order right, left, up and down
+    ///
+    /// The positions aren't checked against any grid, so they can be negative
+    pub fn neighbors4(self) -> [Pos; 4] {
+        [self.right(), self.left(), self.up(), self.down()]
+    }
+
+    /// The orthogonal and diagonal neighbors, starting at the top left one
+    /// and going row by row
+    ///
+    /// The positions aren't checked against any grid, so they can be negative
+    pub fn neighbors8(self) -> [Pos; 8] {
+        [
+            self.up().left(),
+            self.up(),
+            self.up().right(),
+            self.left(),
+            self.right(),
+            self.down().left(),
+            self.down(),
+            self.down().right(),
+        ]
+    }
+
+    /// The distance to the other position moving only orthogonally
+    ///
+    /// ```.rust
+    /// use das_grid::Pos;
+    ///
+    /// assert_eq!(Pos::new(0, 0).manhattan(Pos::new(2, -3)), 5);
+    /// ```
+    pub fn manhattan(self, other: Pos) -> i32 {
+        (self - other).manhattan()
+    }
+
+    /// The distance to the other position when diagonal moves are allowed
+    ///
+    /// ```.rust
+    /// use das_grid::Pos;
+    ///
+    /// assert_eq!(Pos::new(0, 0).chebyshev(Pos::new(2, -3)), 3);
+    /// ```
+    pub fn chebyshev(self, other: Pos) -> i32 {
+        (self - other).chebyshev()
+    }
+}
+
+impl Offset {
+    /// Creates an offset of the given rows and cols
+    pub const fn new(row: i32, col: i32) -> Self {
+        Self { row, col }
+    }
+
+    /// The length of the offset moving only orthogonally
+    pub fn manhattan(self) -> i32 {
+        self.row.abs() + self.col.abs()
+    }
+
+    /// The length of the offset when diagonal moves are allowed
+    pub fn chebyshev(self) -> i32 {
+        self.row.abs().max(self.col.abs())
+    }
+}
+
+impl From<(i32, i32)> for Pos {
+    fn from((row, col): (i32, i32)) -> Self {
+        Pos::new(row, col)
+    }
+}
+
+impl From<Pos> for (i32, i32) {
+    fn from(pos: Pos) -> Self {
+        (pos.row, pos.col)
+    }
+}
+
+impl From<(i32, i32)> for Offset {
+    fn from((row, col): (i32, i32)) -> Self {
+        Offset::new(row, col)
+    }
+}
+
+impl From<Offset> for (i32, i32) {
+    fn from(offset: Offset) -> Self {
+        (offset.row, offset.col)
+    }
+}
+
+impl From<Pos> for Offset {
+    fn from(pos: Pos) -> Self {
+        Offset::new(pos.row, pos.col)
+    }
+}
+
+impl From<MoveDirection> for Offset {
+    fn from(direction: MoveDirection) -> Self {
+        direction.offset()
+    }
+}
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(row: {} col: {})", self.row, self.col)
+    }
+}
+
+impl fmt::Display for Offset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+impl Add<Offset> for Pos {
+    type Output = Pos;
+    fn add(self, rhs: Offset) -> Pos {
+        Pos::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl AddAssign<Offset> for Pos {
+    fn add_assign(&mut self, rhs: Offset) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Offset> for Pos {
+    type Output = Pos;
+    fn sub(self, rhs: Offset) -> Pos {
+        Pos::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl SubAssign<Offset> for Pos {
+    fn sub_assign(&mut self, rhs: Offset) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub<Pos> for Pos {
+    type Output = Offset;
+    fn sub(self, rhs: Pos) -> Offset {
+        Offset::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl Add for Offset {
+    type Output = Offset;
+    fn add(self, rhs: Offset) -> Offset {
+        Offset::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl AddAssign for Offset {
+    fn add_assign(&mut self, rhs: Offset) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Offset {
+    type Output = Offset;
+    fn sub(self, rhs: Offset) -> Offset {
+        Offset::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl SubAssign for Offset {
+    fn sub_assign(&mut self, rhs: Offset) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Offset {
+    type Output = Offset;
+    fn neg(self) -> Offset {
+        Offset::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<i32> for Offset {
+    type Output = Offset;
+    fn mul(self, rhs: i32) -> Offset {
+        Offset::new(self.row * rhs, self.col * rhs)
+    }
+}
@@ -0,0 +1,108 @@
+#![allow(warnings, unused)]
+#[macro_use]
+use pretty_assertions::{assert_eq, assert_ne};
+
+use crate::{Grid, GridErr, MoveDirection, Offset, Pos, MOVE_DOWN, MOVE_LEFT, MOVE_RIGHT, MOVE_UP};
+
+#[test]
+fn test_pos_from_tuple() {
+    let pos: Pos = (3, 7).into();
+    assert_eq!(pos, Pos::new(3, 7));
+    assert_eq!(pos.row, 3);
+    assert_eq!(pos.col, 7);
+
+    let tuple: (i32, i32) = pos.into();
+    assert_eq!(tuple, (3, 7));
+
+    let offset: Offset = (-1, 2).into();
+    assert_eq!(offset, Offset::new(-1, 2));
+    assert_eq!(<(i32, i32)>::from(offset), (-1, 2));
+}
+
+#[test]
+fn test_pos_arithmetic() {
+    let mut pos = Pos::new(2, 2);
+    assert_eq!(pos + Offset::new(1, -2), Pos::new(3, 0));
+    assert_eq!(pos - Offset::new(1, -2), Pos::new(1, 4));
+    assert_eq!(Pos::new(5, 1) - pos, Offset::new(3, -1));
+
+    pos += Offset::new(0, 3);
+    assert_eq!(pos, Pos::new(2, 5));
+    pos -= Offset::new(2, 0);
+    assert_eq!(pos, Pos::new(0, 5));
+
+    let mut offset = Offset::new(1, 2) + Offset::new(3, 4);
+    assert_eq!(offset, Offset::new(4, 6));
+    assert_eq!(offset - Offset::new(1, 1), Offset::new(3, 5));
+    assert_eq!(-offset, Offset::new(-4, -6));
+    assert_eq!(offset * 3, Offset::new(12, 18));
+    offset += Offset::new(1, 1);
+    offset -= Offset::new(0, 2);
+    assert_eq!(offset, Offset::new(5, 5));
+
+    assert_eq!(Pos::new(1, 1).manhattan(Pos::new(4, -1)), 5);
+    assert_eq!(Pos::new(1, 1).chebyshev(Pos::new(4, -1)), 3);
+}
+
+#[test]
+fn test_pos_neighbors() {
+    let pos = Pos::new(4, 4);
+    assert_eq!(pos.up(), Pos::new(3, 4));
+    assert_eq!(pos.down(), Pos::new(5, 4));
+    assert_eq!(pos.left(), Pos::new(4, 3));
+    assert_eq!(pos.right(), Pos::new(4, 5));
+
+    assert_eq!(
+        pos.neighbors4(),
+        [(4, 5).into(), (4, 3).into(), (3, 4).into(), (5, 4).into()]
+    );
+
+    let neighbors = pos.neighbors8();
+    assert_eq!(neighbors.len(), 8);
+    assert!(neighbors.iter().all(|n| pos.chebyshev(*n) == 1));
+    assert!(!neighbors.contains(&pos));
+}
+
+#[test]
+fn test_move_direction_offset() {
+    assert_eq!(MoveDirection::Right.offset(), Offset::from(MOVE_RIGHT));
+    assert_eq!(MoveDirection::Left.offset(), Offset::from(MOVE_LEFT));
+    assert_eq!(MoveDirection::Up.offset(), Offset::from(MOVE_UP));
+    assert_eq!(MoveDirection::Down.offset(), Offset::from(MOVE_DOWN));
+    assert_eq!(Offset::from(MoveDirection::Down), Offset::new(1, 0));
+    assert_eq!(Pos::new(0, 0).step(MoveDirection::Right), Pos::new(0, 1));
+}
+
+#[test]
+fn test_grid_with_pos() {
+    let mut g = Grid::new((3, 4), (1., 1.), 0);
+    let pos = Pos::new(1, 3);
+
+    assert!(g.set(pos, 7).is_ok());
+    assert_eq!(g.get((1, 3)).unwrap(), &7);
+    assert_eq!(g[pos], 7);
+    assert_eq!(g[(1, 3)], 7);
+
+    g[pos.left()] = 5;
+    assert_eq!(g.get(Pos::new(1, 2)).unwrap(), &5);
+
+    assert!(g.mov_to(pos, MoveDirection::Down).is_ok());
+    assert_eq!(g.get(pos.down()).unwrap(), &7);
+    assert_eq!(
+        g.mov_to(pos.down(), MoveDirection::Right),
+        Err(GridErr::OutOfGrid {
+            pos: pos.down().right()
+        })
+    );
+
+    assert_eq!(
+        g.get(Pos::new(-1, 0)),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(-1, 0)
+        })
+    );
+
+    let positions = g.positions().collect::<Vec<_>>();
+    assert_eq!(positions.len(), 12);
+    assert_eq!(positions[5], Pos::new(1, 1));
+}
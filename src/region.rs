@@ -0,0 +1,186 @@
+use std::collections::VecDeque;
+
+use crate::pathfinding;
+use crate::{Connectivity, Grid, GridErr, GridRead, GridWrite, Pos};
+
+/// Represents a connected region found by `connected_components`
+///
+/// The bounding box goes from `min` to `max`, both inclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Region {
+    pub label: usize,
+    pub size: usize,
+    pub min: Pos,
+    pub max: Pos,
+}
+
+impl<T> Grid<T> {
+    /// Replaces the value of every cell connected to the position that matches the predicate,
+    /// like the paint bucket tool
+    ///
+    /// Returns how many cells were filled, 0 if the cell on the position doesn't match
+    ///
+    /// If the position is out of the grid it return an error of GridErr::OutOfGrid
+    ///
+    /// ```.rust
+    /// use das_grid::{Connectivity, Grid};
+    ///
+    /// let mut grid = Grid::new_from_vector((3, 3), (1., 1.), vec![
+    ///     0, 0, 1,
+    ///     1, 1, 0,
+    ///     0, 0, 0,
+    /// ]);
+    /// let filled = grid.flood_fill((0, 0), 7, |v| *v == 0, Connectivity::Four).unwrap();
+    /// assert_eq!(filled, 2);
+    /// assert_eq!(grid.get_flatten_grid(), vec![7, 7, 1, 1, 1, 0, 0, 0, 0]);
+    /// ```
+    pub fn flood_fill<P>(
+        &mut self,
+        pos: impl Into<Pos>,
+        new_value: T,
+        predicate: P,
+        connectivity: Connectivity,
+    ) -> Result<usize, GridErr>
+    where
+        T: Clone,
+        P: Fn(&T) -> bool,
+    {
+        GridWrite::flood_fill(self, pos, new_value, predicate, connectivity)
+    }
+
+    /// Labels the regions of connected cells, two neighbor cells are in the same
+    /// region when `eq` returns true for their values
+    ///
+    /// Returns a grid with the label of each cell and the stats of each region,
+    /// the labels are the index of the regions, numbered row by row
+    ///
+    /// ```.rust
+    /// use das_grid::{Connectivity, Grid};
+    ///
+    /// let grid = Grid::new_from_vector((2, 3), (1., 1.), vec![
+    ///     'a', 'a', 'b',
+    ///     'b', 'a', 'b',
+    /// ]);
+    /// let (labels, regions) = grid.connected_components(|a, b| a == b, Connectivity::Four);
+    /// assert_eq!(labels.get_flatten_grid(), vec![0, 0, 1, 2, 0, 1]);
+    /// assert_eq!(regions[0].size, 3);
+    /// assert_eq!(regions.len(), 3);
+    /// ```
+    pub fn connected_components<E>(
+        &self,
+        eq: E,
+        connectivity: Connectivity,
+    ) -> (Grid<usize>, Vec<Region>)
+    where
+        E: Fn(&T, &T) -> bool,
+    {
+        GridRead::connected_components(self, eq, connectivity)
+    }
+}
+
+// Labels the regions of connected cells, returns the label of each cell row by row
+pub(crate) fn connected_components<G, E>(
+    grid: &G,
+    eq: &E,
+    connectivity: Connectivity,
+) -> (Vec<usize>, Vec<Region>)
+where
+    G: GridRead + ?Sized,
+    E: Fn(&G::Item, &G::Item) -> bool,
+{
+    let mut visited = vec![false; pathfinding::area(grid)];
+    let mut labels = vec![0; pathfinding::area(grid)];
+    let mut regions = vec![];
+
+    for (pos, _) in grid.iter() {
+        let idx = pathfinding::index(grid, pos);
+        if visited[idx] {
+            continue;
+        }
+
+        let region = collect_region(grid, pos, connectivity, eq, &mut visited);
+
+        let label = regions.len();
+        let mut stats = Region {
+            label,
+            size: region.len(),
+            min: pos,
+            max: pos,
+        };
+        for p in region {
+            labels[pathfinding::index(grid, p)] = label;
+            stats.min = Pos::new(stats.min.row.min(p.row), stats.min.col.min(p.col));
+            stats.max = Pos::new(stats.max.row.max(p.row), stats.max.col.max(p.col));
+        }
+        regions.push(stats);
+    }
+
+    (labels, regions)
+}
+
+// Replaces every cell connected to the position, already wrapped inside of the grid,
+// that matches the predicate
+pub(crate) fn flood_fill<G, P>(
+    grid: &mut G,
+    pos: Pos,
+    new_value: G::Item,
+    predicate: &P,
+    connectivity: Connectivity,
+) -> Result<usize, GridErr>
+where
+    G: GridWrite + ?Sized,
+    G::Item: Clone,
+    P: Fn(&G::Item) -> bool,
+{
+    if !predicate(grid.get(pos)?) {
+        return Ok(0);
+    }
+
+    let mut visited = vec![false; pathfinding::area(grid)];
+    let region = collect_region(
+        grid,
+        pos,
+        connectivity,
+        &|_, next| predicate(next),
+        &mut visited,
+    );
+    for &p in &region {
+        grid.set(p, new_value.clone())?;
+    }
+
+    Ok(region.len())
+}
+
+// Breadth first walk from the position, a neighbor is reached when `linked` returns true
+// for the value of the cell it is reached from and its own value,
+// iterative so big grids don't overflow the stack
+fn collect_region<G>(
+    grid: &G,
+    pos: Pos,
+    connectivity: Connectivity,
+    linked: &dyn Fn(&G::Item, &G::Item) -> bool,
+    visited: &mut [bool],
+) -> Vec<Pos>
+where
+    G: GridRead + ?Sized,
+{
+    let mut region = vec![];
+    let mut queue = VecDeque::from([pos]);
+    visited[pathfinding::index(grid, pos)] = true;
+
+    while let Some(pos) = queue.pop_front() {
+        region.push(pos);
+        let Ok(current) = grid.get(pos) else {
+            continue;
+        };
+        for next in pathfinding::steps(grid, pos, connectivity, &|v| linked(current, v)) {
+            let idx = pathfinding::index(grid, next);
+            if !visited[idx] {
+                visited[idx] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+
+    region
+}
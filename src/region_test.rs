@@ -0,0 +1,139 @@
+#![allow(warnings, unused)]
+#[macro_use]
+use pretty_assertions::{assert_eq, assert_ne};
+
+use crate::{Connectivity, Grid, GridErr, Pos, Region};
+
+#[test]
+fn test_flood_fill() {
+    let board = "\
+        ..#.\
+        .#..\
+        #...\
+        ..##\
+        ";
+
+    let mut g = Grid::new_from_vector((4, 4), (1., 1.), board.chars().collect());
+    let filled = g.flood_fill((0, 0), 'o', |c| *c == '.', Connectivity::Four);
+    assert_eq!(filled, Ok(3));
+    assert_eq!(g.get_row(0).unwrap(), vec!['o', 'o', '#', '.']);
+    assert_eq!(g.get_row(1).unwrap(), vec!['o', '#', '.', '.']);
+
+    // Filling with a value that still matches must not loop forever
+    let mut g = Grid::new_from_vector((4, 4), (1., 1.), board.chars().collect());
+    assert_eq!(
+        g.flood_fill((3, 0), '.', |c| *c == '.', Connectivity::Four),
+        Ok(8)
+    );
+
+    let mut g = Grid::new_from_vector((4, 4), (1., 1.), board.chars().collect());
+    assert_eq!(
+        g.flood_fill((0, 2), 'o', |c| *c == '.', Connectivity::Four),
+        Ok(0)
+    );
+    assert_eq!(g.get((0, 2)), Ok(&'#'));
+    assert_eq!(
+        g.flood_fill((4, 0), 'o', |c| *c == '.', Connectivity::Four),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(4, 0)
+        })
+    );
+}
+
+#[test]
+fn test_flood_fill_eight() {
+    let board = "\
+        ..#.\
+        .#..\
+        #...\
+        ..##\
+        ";
+
+    let mut g = Grid::new_from_vector((4, 4), (1., 1.), board.chars().collect());
+    let eight = Connectivity::Eight { cut_corners: true };
+    assert_eq!(g.flood_fill((0, 2), 'x', |c| *c == '#', eight), Ok(3));
+    assert_eq!(g.get((2, 0)), Ok(&'x'));
+    assert_eq!(g.get((3, 2)), Ok(&'#'));
+
+    let mut g = Grid::new_from_vector((4, 4), (1., 1.), board.chars().collect());
+    assert_eq!(g.flood_fill((0, 0), 'o', |c| *c == '.', eight), Ok(11));
+}
+
+#[test]
+fn test_flood_fill_big_grid() {
+    let mut g = Grid::new((2048, 2048), (1., 1.), 0u8);
+    let filled = g.flood_fill((1024, 1024), 1, |v| *v == 0, Connectivity::Four);
+    assert_eq!(filled, Ok(2048 * 2048));
+    assert!((&g).into_iter().all(|v| *v == 1));
+}
+
+#[test]
+fn test_connected_components() {
+    let g = Grid::new_from_vector(
+        (4, 4),
+        (1., 1.),
+        "\
+        ..#.\
+        .#..\
+        #...\
+        ..##\
+        "
+        .chars()
+        .collect(),
+    );
+    let (labels, regions) = g.connected_components(|a, b| a == b, Connectivity::Four);
+
+    assert_eq!(labels.rows(), 4);
+    assert_eq!(labels.cols(), 4);
+    assert_eq!(
+        labels.get_flatten_grid(),
+        vec![
+            0, 0, 1, 2, //
+            0, 3, 2, 2, //
+            4, 2, 2, 2, //
+            2, 2, 5, 5, //
+        ]
+    );
+    assert_eq!(regions.len(), 6);
+    assert_eq!(
+        regions[0],
+        Region {
+            label: 0,
+            size: 3,
+            min: Pos::new(0, 0),
+            max: Pos::new(1, 1),
+        }
+    );
+    assert_eq!(regions[2].size, 8);
+    assert_eq!(
+        (regions[2].min, regions[2].max),
+        (Pos::new(0, 0), Pos::new(3, 3))
+    );
+    assert_eq!(
+        (regions[5].min, regions[5].max),
+        (Pos::new(3, 2), Pos::new(3, 3))
+    );
+    assert_eq!(regions.iter().map(|r| r.size).sum::<usize>(), 16);
+
+    let eight = Connectivity::Eight { cut_corners: true };
+    let (_, regions) = g.connected_components(|a, b| a == b, eight);
+    assert_eq!(regions.len(), 3);
+    assert_eq!(regions[0].size, 11);
+    assert_eq!(regions[1].size, 3);
+}
+
+#[test]
+fn test_connected_components_gradient() {
+    // Each cell is compared with the neighbor it is reached from, not with the first one
+    let g = Grid::new_from_vector((2, 4), (1., 1.), vec![1, 2, 3, 4, 9, 9, 9, 7]);
+    let (labels, regions) =
+        g.connected_components(|a: &i32, b: &i32| (a - b).abs() <= 1, Connectivity::Four);
+
+    assert_eq!(labels.get_flatten_grid(), vec![0, 0, 0, 0, 1, 1, 1, 2]);
+    assert_eq!(regions.len(), 3);
+    assert_eq!(regions[0].size, 4);
+    assert_eq!(
+        (regions[0].min, regions[0].max),
+        (Pos::new(0, 0), Pos::new(0, 3))
+    );
+}
@@ -0,0 +1,325 @@
+use crate::{Grid, GridErr, Offset, Pos};
+
+/// Represents what a rule knows about the change being checked
+///
+/// `value` is the value arriving on `dst`, on a move it is the value leaving `src`,
+/// on a set or a stamp there is no `src` and it is the new value
+///
+/// The grid is the state before the change, since no cell is changed until
+/// every rule passes
+pub struct RuleContext<'a, T> {
+    pub src: Option<Pos>,
+    pub dst: Pos,
+    pub src_value: Option<&'a T>,
+    pub dst_value: &'a T,
+    pub value: &'a T,
+    pub grid: &'a Grid<T>,
+}
+
+/// Represents a rule checked before the grid is changed
+///
+/// Any function or closure `Fn(&RuleContext<T>) -> Result<(), E>` is a rule,
+/// the error can be any type built from a `GridErr`
+///
+/// ```.rust
+/// use das_grid::{GridErr, Rule, RuleContext};
+///
+/// struct NotOccupied;
+///
+/// impl Rule<char> for NotOccupied {
+///     fn check(&self, ctx: &RuleContext<char>) -> Result<(), GridErr> {
+///         if *ctx.dst_value != '.' {
+///             return Err(GridErr::RuleFailed(None));
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait Rule<T, E = GridErr> {
+    fn check(&self, ctx: &RuleContext<T>) -> Result<(), E>;
+}
+
+impl<T, E, F> Rule<T, E> for F
+where
+    F: Fn(&RuleContext<T>) -> Result<(), E>,
+{
+    fn check(&self, ctx: &RuleContext<T>) -> Result<(), E> {
+        self(ctx)
+    }
+}
+
+/// Represents how a rule set reports the failures
+///
+/// RuleMode::FailFast stops on the first failure
+///
+/// RuleMode::CollectAll runs every rule and reports every failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RuleMode {
+    #[default]
+    FailFast,
+    CollectAll,
+}
+
+/// Represents a group of rules checked together before any change of the grid
+///
+/// The failures are returned as a `Vec<E>`, with a single error on RuleMode::FailFast
+///
+/// ```.rust
+/// use das_grid::{Grid, GridErr, Pos, RuleContext, RuleMode, RuleSet};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Why {
+///     Grid(GridErr),
+///     Occupied,
+///     TooFar,
+/// }
+///
+/// impl From<GridErr> for Why {
+///     fn from(err: GridErr) -> Self {
+///         Why::Grid(err)
+///     }
+/// }
+///
+/// let mut rules = RuleSet::new(RuleMode::CollectAll);
+/// rules.push(|ctx: &RuleContext<i32>| if *ctx.dst_value != 0 { Err(Why::Occupied) } else { Ok(()) });
+/// rules.push(|ctx: &RuleContext<i32>| match ctx.src {
+///     Some(src) if src.chebyshev(ctx.dst) > 1 => Err(Why::TooFar),
+///     _ => Ok(()),
+/// });
+///
+/// let mut grid = Grid::new_from_vector((1, 3), (1., 1.), vec![1, 0, 2]);
+/// assert_eq!(grid.mov_with_ruleset((0, 0), (0, 2), &rules), Err(vec![Why::Occupied, Why::TooFar]));
+/// assert_eq!(
+///     grid.mov_with_ruleset((0, 0), (0, 3), &rules),
+///     Err(vec![Why::Grid(GridErr::OutOfGrid { pos: Pos::new(0, 3) })])
+/// );
+/// assert_eq!(grid.mov_with_ruleset((0, 0), (0, 1), &rules), Ok(0));
+/// ```
+pub struct RuleSet<'r, T, E = GridErr> {
+    rules: Vec<Box<dyn Rule<T, E> + 'r>>,
+    mode: RuleMode,
+}
+
+impl<'r, T, E> RuleSet<'r, T, E> {
+    /// Creates an empty rule set reporting the failures with the given mode
+    pub fn new(mode: RuleMode) -> Self {
+        Self {
+            rules: vec![],
+            mode,
+        }
+    }
+
+    /// Adds a rule to the set, the rules are checked in the order they are added
+    pub fn push(&mut self, rule: impl Rule<T, E> + 'r) {
+        self.rules.push(Box::new(rule));
+    }
+
+    /// The mode used to report the failures
+    pub fn mode(&self) -> RuleMode {
+        self.mode
+    }
+
+    /// The amount of rules in the set
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Returns true if the set has no rules
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Checks every rule against the context, adding the failures to the errors
+    ///
+    /// Returns false when the checks must stop, on the first failure of RuleMode::FailFast
+    pub fn check(&self, ctx: &RuleContext<T>, errors: &mut Vec<E>) -> bool {
+        for rule in &self.rules {
+            if let Err(err) = rule.check(ctx) {
+                errors.push(err);
+                if self.mode == RuleMode::FailFast {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+impl<'r, T, E> Default for RuleSet<'r, T, E> {
+    fn default() -> Self {
+        Self::new(RuleMode::default())
+    }
+}
+
+impl<T> Grid<T> {
+    /// Sets a given value to the position (row, col)
+    /// Only if every rule of the set passes
+    ///
+    /// If the position is out of the grid the error GridErr::OutOfGrid is returned
+    /// before any rule is checked
+    ///
+    /// ```.rust
+    /// use das_grid::{Grid, GridErr, RuleContext, RuleMode, RuleSet};
+    ///
+    /// let mut rules = RuleSet::new(RuleMode::FailFast);
+    /// rules.push(|ctx: &RuleContext<i32>| {
+    ///     if *ctx.value > 9 {
+    ///         return Err(GridErr::RuleFailed(None));
+    ///     }
+    ///     Ok(())
+    /// });
+    ///
+    /// let mut grid = Grid::new((2, 2), (1., 1.), 0);
+    /// assert_eq!(grid.set_with_ruleset((0, 1), 5, &rules), Ok(()));
+    /// assert_eq!(grid.set_with_ruleset((0, 1), 10, &rules), Err(vec![GridErr::RuleFailed(None)]));
+    /// assert_eq!(grid.get((0, 1)), Ok(&5));
+    /// ```
+    pub fn set_with_ruleset<E>(
+        &mut self,
+        dst: impl Into<Pos>,
+        value: T,
+        rules: &RuleSet<T, E>,
+    ) -> Result<(), Vec<E>>
+    where
+        E: From<GridErr>,
+    {
+        let dst = dst.into();
+        let dst_value = self.get(dst).map_err(|err| vec![err.into()])?;
+
+        let mut errors = vec![];
+        rules.check(
+            &RuleContext {
+                src: None,
+                dst,
+                src_value: None,
+                dst_value,
+                value: &value,
+                grid: self,
+            },
+            &mut errors,
+        );
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let idx = self.cell_index(dst);
+        self.cells[idx] = value;
+        Ok(())
+    }
+
+    /// Moves a given value from position (row, col) to destiny position (row, col)
+    /// Only if every rule of the set passes
+    ///
+    /// If any of the positions is out of the grid the error GridErr::OutOfGrid is returned
+    /// before any rule is checked
+    ///
+    /// The value that was on the destiny is returned, as `Grid::mov` does
+    pub fn mov_with_ruleset<E>(
+        &mut self,
+        src: impl Into<Pos>,
+        dst: impl Into<Pos>,
+        rules: &RuleSet<T, E>,
+    ) -> Result<T, Vec<E>>
+    where
+        T: Clone,
+        E: From<GridErr>,
+    {
+        let (src, dst) = (src.into(), dst.into());
+        let src_value = self.get(src).map_err(|err| vec![err.into()])?;
+        let dst_value = self.get(dst).map_err(|err| vec![err.into()])?;
+
+        let mut errors = vec![];
+        rules.check(
+            &RuleContext {
+                src: Some(src),
+                dst,
+                src_value: Some(src_value),
+                dst_value,
+                value: src_value,
+                grid: self,
+            },
+            &mut errors,
+        );
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        self.mov(src, dst).map_err(|err| vec![err.into()])
+    }
+
+    /// Stamps the subgrid into the destiny grid, merging both
+    /// Only if every rule of the set passes for every cell of the subgrid
+    ///
+    /// The rules are checked for each cell, on RuleMode::CollectAll the failures
+    /// of every cell are returned
+    ///
+    /// If the sub grid is greater than the main grid the error GridErr::SubgridOverflow is returned,
+    /// and the cells that bleed out of the grid fail with GridErr::OutOfGrid
+    ///
+    /// ```.rust
+    /// use das_grid::{Grid, GridErr, RuleContext, RuleMode, RuleSet};
+    ///
+    /// let mut rules = RuleSet::new(RuleMode::CollectAll);
+    /// rules.push(|ctx: &RuleContext<i32>| {
+    ///     if *ctx.dst_value != 0 {
+    ///         return Err(GridErr::RuleFailed(None));
+    ///     }
+    ///     Ok(())
+    /// });
+    ///
+    /// let mut grid = Grid::new_from_vector((2, 2), (1., 1.), vec![0, 1, 1, 0]);
+    /// let sub_grid = Grid::new((2, 2), (1., 1.), 5);
+    /// let errors = grid.stamp_subgrid_with_ruleset((0, 0), sub_grid, &rules).unwrap_err();
+    /// assert_eq!(errors.len(), 2);
+    /// assert_eq!(grid.get_flatten_grid(), vec![0, 1, 1, 0]);
+    /// ```
+    pub fn stamp_subgrid_with_ruleset<E>(
+        &mut self,
+        dst: impl Into<Pos>,
+        sub_grid: Grid<T>,
+        rules: &RuleSet<T, E>,
+    ) -> Result<(), Vec<E>>
+    where
+        E: From<GridErr>,
+    {
+        let dst = dst.into();
+        self.check_grid_overflow(&sub_grid)
+            .and_then(|_| self.check_grid_bounds(dst))
+            .map_err(|err| vec![err.into()])?;
+
+        let mut errors = vec![];
+        for (sub_index, subv) in sub_grid.positions().zip(sub_grid.cells.iter()) {
+            let dest = dst + Offset::from(sub_index);
+            let keep_going = match self.get(dest) {
+                Ok(dst_value) => rules.check(
+                    &RuleContext {
+                        src: None,
+                        dst: dest,
+                        src_value: None,
+                        dst_value,
+                        value: subv,
+                        grid: self,
+                    },
+                    &mut errors,
+                ),
+                Err(err) => {
+                    errors.push(err.into());
+                    rules.mode() == RuleMode::CollectAll
+                }
+            };
+            if !keep_going {
+                break;
+            }
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        for (sub_index, subv) in sub_grid.positions().zip(sub_grid.cells) {
+            let idx = self.cell_index(dst + Offset::from(sub_index));
+            self.cells[idx] = subv;
+        }
+
+        Ok(())
+    }
+}
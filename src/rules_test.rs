@@ -0,0 +1,206 @@
+#![allow(warnings, unused)]
+#[macro_use]
+use pretty_assertions::{assert_eq, assert_ne};
+
+use crate::{Grid, GridErr, Pos, Rule, RuleContext, RuleMode, RuleSet};
+
+#[derive(Debug, Clone, PartialEq)]
+enum MoveErr {
+    Grid(GridErr),
+    Occupied(Pos),
+    NotYourPiece(char),
+    Blocked,
+}
+
+impl From<GridErr> for MoveErr {
+    fn from(err: GridErr) -> Self {
+        MoveErr::Grid(err)
+    }
+}
+
+struct Occupied;
+
+impl Rule<char, MoveErr> for Occupied {
+    fn check(&self, ctx: &RuleContext<char>) -> Result<(), MoveErr> {
+        if *ctx.dst_value != '.' {
+            return Err(MoveErr::Occupied(ctx.dst));
+        }
+        Ok(())
+    }
+}
+
+// White pieces are upper case
+fn only_white(ctx: &RuleContext<char>) -> Result<(), MoveErr> {
+    if !ctx.value.is_uppercase() {
+        return Err(MoveErr::NotYourPiece(*ctx.value));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_mov_with_ruleset_context() {
+    let mut g = Grid::new_from_vector(
+        (3, 3),
+        (1., 1.),
+        vec!['K', '.', 'p', '.', '#', '.', 'r', '.', '.'],
+    );
+    let seen = std::cell::RefCell::new(vec![]);
+
+    let mut rules = RuleSet::new(RuleMode::FailFast);
+    rules.push(|ctx: &RuleContext<char>| {
+        seen.borrow_mut().push((
+            ctx.src,
+            ctx.dst,
+            ctx.src_value.copied(),
+            *ctx.dst_value,
+            *ctx.value,
+            ctx.grid[(1, 1)],
+        ));
+        Ok::<(), MoveErr>(())
+    });
+
+    assert_eq!(g.mov_with_ruleset((0, 0), (0, 1), &rules), Ok('.'));
+    drop(rules);
+    assert_eq!(
+        seen.into_inner(),
+        vec![(
+            Some(Pos::new(0, 0)),
+            Pos::new(0, 1),
+            Some('K'),
+            '.',
+            'K',
+            '#'
+        )]
+    );
+    assert_eq!(g.get((0, 1)), Ok(&'K'));
+}
+
+#[test]
+fn test_fail_fast_and_collect_all() {
+    let mut fail_fast = RuleSet::new(RuleMode::FailFast);
+    fail_fast.push(only_white);
+    fail_fast.push(Occupied);
+
+    let mut collect_all = RuleSet::new(RuleMode::CollectAll);
+    collect_all.push(only_white);
+    collect_all.push(Occupied);
+    collect_all.push(|ctx: &RuleContext<char>| {
+        if ctx.grid[(1, 1)] == '#' && ctx.dst == Pos::new(0, 2) {
+            return Err(MoveErr::Blocked);
+        }
+        Ok(())
+    });
+    assert_eq!(collect_all.len(), 3);
+
+    let mut g = Grid::new_from_vector(
+        (3, 3),
+        (1., 1.),
+        vec!['K', '.', 'p', '.', '#', '.', 'r', '.', '.'],
+    );
+    assert_eq!(
+        g.mov_with_ruleset((2, 0), (0, 2), &fail_fast),
+        Err(vec![MoveErr::NotYourPiece('r')])
+    );
+    assert_eq!(
+        g.mov_with_ruleset((2, 0), (0, 2), &collect_all),
+        Err(vec![
+            MoveErr::NotYourPiece('r'),
+            MoveErr::Occupied(Pos::new(0, 2)),
+            MoveErr::Blocked,
+        ])
+    );
+    assert_eq!(
+        g.mov_with_ruleset((2, 0), (3, 0), &collect_all),
+        Err(vec![MoveErr::Grid(GridErr::OutOfGrid {
+            pos: Pos::new(3, 0)
+        })])
+    );
+
+    // Nothing changed
+    assert_eq!(
+        g.get_flatten_grid(),
+        vec!['K', '.', 'p', '.', '#', '.', 'r', '.', '.']
+    );
+}
+
+#[test]
+fn test_set_with_ruleset() {
+    let mut g = Grid::new_from_vector(
+        (3, 3),
+        (1., 1.),
+        vec!['K', '.', 'p', '.', '#', '.', 'r', '.', '.'],
+    );
+    let mut rules = RuleSet::new(RuleMode::CollectAll);
+    rules.push(Occupied);
+    rules.push(only_white);
+
+    assert_eq!(
+        g.set_with_ruleset((1, 1), 'q', &rules),
+        Err(vec![
+            MoveErr::Occupied(Pos::new(1, 1)),
+            MoveErr::NotYourPiece('q')
+        ])
+    );
+    assert_eq!(g.set_with_ruleset((1, 0), 'Q', &rules), Ok(()));
+    assert_eq!(g.get((1, 0)), Ok(&'Q'));
+
+    let empty: RuleSet<char> = RuleSet::default();
+    assert!(empty.is_empty());
+    assert_eq!(g.set_with_ruleset((1, 1), 'x', &empty), Ok(()));
+    assert_eq!(
+        g.set_with_ruleset((5, 1), 'x', &empty),
+        Err(vec![GridErr::OutOfGrid {
+            pos: Pos::new(5, 1)
+        }])
+    );
+}
+
+#[test]
+fn test_stamp_subgrid_with_ruleset() {
+    let mut rules = RuleSet::new(RuleMode::CollectAll);
+    rules.push(Occupied);
+
+    let mut g = Grid::new_from_vector(
+        (3, 3),
+        (1., 1.),
+        vec!['K', '.', 'p', '.', '#', '.', 'r', '.', '.'],
+    );
+    let sub = Grid::new((2, 2), (1., 1.), 'W');
+    assert_eq!(
+        g.stamp_subgrid_with_ruleset((0, 0), sub, &rules),
+        Err(vec![
+            MoveErr::Occupied(Pos::new(0, 0)),
+            MoveErr::Occupied(Pos::new(1, 1))
+        ])
+    );
+
+    let sub = Grid::new((2, 2), (1., 1.), 'W');
+    assert_eq!(
+        g.stamp_subgrid_with_ruleset((1, 2), sub, &rules),
+        Err(vec![
+            MoveErr::Grid(GridErr::OutOfGrid {
+                pos: Pos::new(1, 3)
+            }),
+            MoveErr::Grid(GridErr::OutOfGrid {
+                pos: Pos::new(2, 3)
+            })
+        ])
+    );
+    assert_eq!(
+        g.get_flatten_grid(),
+        vec!['K', '.', 'p', '.', '#', '.', 'r', '.', '.']
+    );
+
+    let mut fail_fast = RuleSet::new(RuleMode::FailFast);
+    fail_fast.push(Occupied);
+    let sub = Grid::new((2, 2), (1., 1.), 'W');
+    assert_eq!(
+        g.stamp_subgrid_with_ruleset((0, 0), sub, &fail_fast)
+            .map_err(|e| e.len()),
+        Err(1)
+    );
+
+    let sub = Grid::new((1, 2), (1., 1.), 'W');
+    assert_eq!(g.stamp_subgrid_with_ruleset((2, 1), sub, &rules), Ok(()));
+    assert_eq!(g.get_row(2).unwrap(), vec!['r', 'W', 'W']);
+}
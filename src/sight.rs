@@ -0,0 +1,360 @@
+use crate::pathfinding;
+use crate::{DistanceMetric, Grid, GridErr, GridRead, Offset, Pos};
+
+/// Iterator over the positions of a Bresenham line, from the start to the end both included
+///
+/// Each step moves to one of the 8 neighbors, so the line is as thin as possible
+///
+/// ```.rust
+/// use das_grid::{Line, Pos};
+///
+/// let line = Line::new((0, 0), (1, 3)).collect::<Vec<_>>();
+/// assert_eq!(line, vec![Pos::new(0, 0), Pos::new(0, 1), Pos::new(1, 2), Pos::new(1, 3)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Line {
+    pos: Pos,
+    end: Pos,
+    delta: Offset,
+    step: Offset,
+    err: i32,
+    done: bool,
+}
+
+impl Line {
+    /// Creates the line from start to end
+    pub fn new(start: impl Into<Pos>, end: impl Into<Pos>) -> Self {
+        let (start, end) = (start.into(), end.into());
+        let diff = end - start;
+        let delta = Offset::new(-diff.row.abs(), diff.col.abs());
+        Self {
+            pos: start,
+            end,
+            delta,
+            step: Offset::new(diff.row.signum(), diff.col.signum()),
+            err: delta.row + delta.col,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for Line {
+    type Item = Pos;
+
+    fn next(&mut self) -> Option<Pos> {
+        if self.done {
+            return None;
+        }
+
+        let pos = self.pos;
+        if pos == self.end {
+            self.done = true;
+            return Some(pos);
+        }
+
+        let e2 = 2 * self.err;
+        if e2 >= self.delta.row {
+            self.err += self.delta.row;
+            self.pos.col += self.step.col;
+        }
+        if e2 <= self.delta.col {
+            self.err += self.delta.col;
+            self.pos.row += self.step.row;
+        }
+
+        Some(pos)
+    }
+}
+
+/// Iterator over every position touched by the segment from the start to the end,
+/// both included
+///
+/// Each step moves only orthogonally, when the segment passes exactly on a corner
+/// both cells beside the corner are returned
+///
+/// ```.rust
+/// use das_grid::{Pos, Supercover};
+///
+/// let line = Supercover::new((0, 0), (1, 1)).collect::<Vec<_>>();
+/// assert_eq!(line, vec![Pos::new(0, 0), Pos::new(0, 1), Pos::new(1, 0), Pos::new(1, 1)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Supercover {
+    pos: Pos,
+    walked: Offset,
+    total: Offset,
+    step: Offset,
+    corners: [Option<Pos>; 2],
+    done: bool,
+}
+
+impl Supercover {
+    /// Creates the line from start to end
+    pub fn new(start: impl Into<Pos>, end: impl Into<Pos>) -> Self {
+        let (start, end) = (start.into(), end.into());
+        let diff = end - start;
+        Self {
+            pos: start,
+            walked: Offset::default(),
+            total: Offset::new(diff.row.abs(), diff.col.abs()),
+            step: Offset::new(diff.row.signum(), diff.col.signum()),
+            corners: [None, None],
+            done: false,
+        }
+    }
+}
+
+impl Iterator for Supercover {
+    type Item = Pos;
+
+    fn next(&mut self) -> Option<Pos> {
+        if let Some(corner) = self.corners.iter_mut().find_map(Option::take) {
+            return Some(corner);
+        }
+
+        if self.done {
+            return None;
+        }
+
+        let pos = self.pos;
+        if self.walked == self.total {
+            self.done = true;
+            return Some(pos);
+        }
+
+        // Compares where the segment crosses the next col and the next row
+        let decision =
+            (1 + 2 * self.walked.col) * self.total.row - (1 + 2 * self.walked.row) * self.total.col;
+        if decision == 0 {
+            self.corners = [
+                Some(pos + Offset::new(0, self.step.col)),
+                Some(pos + Offset::new(self.step.row, 0)),
+            ];
+            self.pos += self.step;
+            self.walked += Offset::new(1, 1);
+        } else if decision < 0 {
+            self.pos.col += self.step.col;
+            self.walked.col += 1;
+        } else {
+            self.pos.row += self.step.row;
+            self.walked.row += 1;
+        }
+
+        Some(pos)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Returns the cells on the Bresenham line between two positions with their values,
+    /// the cells out of the grid are skipped
+    ///
+    /// ```.rust
+    /// let grid = das_grid::Grid::new_from_vector((3, 3), (1., 1.), (1..=9).collect());
+    /// let values = grid.line((0, 0), (2, 2)).map(|(_, v)| *v).collect::<Vec<_>>();
+    /// assert_eq!(values, vec![1, 5, 9]);
+    /// ```
+    pub fn line(&self, a: impl Into<Pos>, b: impl Into<Pos>) -> impl Iterator<Item = (Pos, &T)> {
+        GridRead::line(self, a, b)
+    }
+
+    /// Returns every cell touched by the segment between two positions with their values,
+    /// the cells out of the grid are skipped
+    ///
+    /// ```.rust
+    /// let grid = das_grid::Grid::new_from_vector((3, 3), (1., 1.), (1..=9).collect());
+    /// let values = grid.supercover_line((0, 0), (1, 1)).map(|(_, v)| *v).collect::<Vec<_>>();
+    /// assert_eq!(values, vec![1, 2, 4, 5]);
+    /// ```
+    pub fn supercover_line(
+        &self,
+        a: impl Into<Pos>,
+        b: impl Into<Pos>,
+    ) -> impl Iterator<Item = (Pos, &T)> {
+        GridRead::supercover_line(self, a, b)
+    }
+
+    /// Checks if there is no opaque cell on the Bresenham line between two positions,
+    /// the positions themselves aren't checked
+    ///
+    /// If any of the positions is out of the grid it return an error of GridErr::OutOfGrid
+    ///
+    /// ```.rust
+    /// let grid = das_grid::Grid::new_from_vector((1, 4), (1., 1.), vec!['.', '.', '#', '.']);
+    /// assert_eq!(grid.line_of_sight((0, 0), (0, 2), |c| *c == '#'), Ok(true));
+    /// assert_eq!(grid.line_of_sight((0, 0), (0, 3), |c| *c == '#'), Ok(false));
+    /// ```
+    pub fn line_of_sight<F>(
+        &self,
+        a: impl Into<Pos>,
+        b: impl Into<Pos>,
+        opaque: F,
+    ) -> Result<bool, GridErr>
+    where
+        F: Fn(&T) -> bool,
+    {
+        GridRead::line_of_sight(self, a, b, opaque)
+    }
+
+    /// Computes which cells can be seen from the origin using symmetric shadowcasting,
+    /// the cells farther than the radius (euclidean) are never visible
+    ///
+    /// Opaque cells are visible but block the sight of the cells behind them,
+    /// if the origin sees a cell that cell also sees the origin
+    ///
+    /// If the origin is out of the grid it return an error of GridErr::OutOfGrid
+    ///
+    /// ```.rust
+    /// let grid = das_grid::Grid::new_from_vector((3, 5), (1., 1.), vec![
+    ///     '.', '.', '.', '.', '.',
+    ///     '.', '.', '#', '.', '.',
+    ///     '.', '.', '.', '.', '.',
+    /// ]);
+    /// let visible = grid.fov((1, 0), 10, |c| *c == '#').unwrap();
+    /// assert_eq!(visible.get((1, 2)), Ok(&true));
+    /// assert_eq!(visible.get((1, 4)), Ok(&false));
+    /// assert_eq!(visible.get((0, 4)), Ok(&true));
+    /// ```
+    pub fn fov<F>(
+        &self,
+        origin: impl Into<Pos>,
+        radius: i32,
+        opaque: F,
+    ) -> Result<Grid<bool>, GridErr>
+    where
+        F: Fn(&T) -> bool,
+    {
+        GridRead::fov(self, origin, radius, opaque)
+    }
+}
+
+// Checks the cells between two positions on the Bresenham line
+pub(crate) fn line_of_sight<G, F>(grid: &G, a: Pos, b: Pos, opaque: &F) -> Result<bool, GridErr>
+where
+    G: GridRead + ?Sized,
+    F: Fn(&G::Item) -> bool,
+{
+    grid.wrap(a)?;
+    grid.wrap(b)?;
+
+    Ok(Line::new(a, b)
+        .filter(|pos| *pos != a && *pos != b)
+        .all(|pos| !grid.get(pos).is_ok_and(opaque)))
+}
+
+// Symmetric shadowcasting from the origin, returns which cells are visible row by row
+pub(crate) fn fov<G, F>(
+    grid: &G,
+    origin: Pos,
+    radius: i32,
+    opaque: &F,
+) -> Result<Vec<bool>, GridErr>
+where
+    G: GridRead + ?Sized,
+    F: Fn(&G::Item) -> bool,
+{
+    let mut visible = vec![false; pathfinding::area(grid)];
+    visible[pathfinding::index(grid, grid.wrap(origin)?)] = true;
+
+    // The cells out of the grid block the sight, like the walls
+    let is_wall = |pos: Pos| grid.get(pos).map(opaque).unwrap_or(true);
+
+    // Each quadrant maps the depth and the col of a scanned row to a position of the grid
+    let quadrants: [fn(Pos, i32, i32) -> Pos; 4] = [
+        |o, depth, col| Pos::new(o.row - depth, o.col + col),
+        |o, depth, col| Pos::new(o.row + depth, o.col + col),
+        |o, depth, col| Pos::new(o.row + col, o.col + depth),
+        |o, depth, col| Pos::new(o.row + col, o.col - depth),
+    ];
+
+    // The cells out of the grid stop the scan, but on the wrapped axes there are none,
+    // past the size of the grid the rows only see the same cells again
+    let (grid_rows, grid_cols) = grid.dimensions();
+    let max_depth = radius.min(grid_rows.max(grid_cols));
+
+    for transform in quadrants {
+        let mut rows = vec![ScanRow {
+            depth: 1,
+            start: Slope::new(-1, 1),
+            end: Slope::new(1, 1),
+        }];
+
+        while let Some(mut row) = rows.pop() {
+            if row.depth > max_depth {
+                continue;
+            }
+
+            let mut prev_wall = None;
+            for col in row.min_col()..=row.max_col() {
+                let pos = transform(origin, row.depth, col);
+                let wall = is_wall(pos);
+
+                let in_radius = DistanceMetric::Euclidean.within(pos - origin, radius);
+                if in_radius && (wall || row.is_symmetric(col)) {
+                    if let Ok(pos) = grid.wrap(pos) {
+                        visible[pathfinding::index(grid, pos)] = true;
+                    }
+                }
+
+                if prev_wall == Some(true) && !wall {
+                    row.start = Slope::new(2 * col - 1, 2 * row.depth);
+                }
+                if prev_wall == Some(false) && wall {
+                    rows.push(ScanRow {
+                        depth: row.depth + 1,
+                        start: row.start,
+                        end: Slope::new(2 * col - 1, 2 * row.depth),
+                    });
+                }
+                prev_wall = Some(wall);
+            }
+
+            if prev_wall == Some(false) {
+                rows.push(ScanRow {
+                    depth: row.depth + 1,
+                    ..row
+                });
+            }
+        }
+    }
+
+    Ok(visible)
+}
+
+// A slope of the shadowcasting as a fraction, the denominator is always positive
+#[derive(Debug, Clone, Copy)]
+struct Slope {
+    num: i32,
+    den: i32,
+}
+
+impl Slope {
+    fn new(num: i32, den: i32) -> Self {
+        Self { num, den }
+    }
+}
+
+// A row of the shadowcasting between two slopes
+#[derive(Debug, Clone, Copy)]
+struct ScanRow {
+    depth: i32,
+    start: Slope,
+    end: Slope,
+}
+
+impl ScanRow {
+    // depth * start rounded with ties going up
+    fn min_col(&self) -> i32 {
+        (2 * self.depth * self.start.num + self.start.den).div_euclid(2 * self.start.den)
+    }
+
+    // depth * end rounded with ties going down
+    fn max_col(&self) -> i32 {
+        -(self.end.den - 2 * self.depth * self.end.num).div_euclid(2 * self.end.den)
+    }
+
+    // The col is inside of the slopes, so the cell is seen from the origin and vice versa
+    fn is_symmetric(&self, col: i32) -> bool {
+        col * self.start.den >= self.depth * self.start.num
+            && col * self.end.den <= self.depth * self.end.num
+    }
+}
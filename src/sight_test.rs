@@ -0,0 +1,175 @@
+#![allow(warnings, unused)]
+#[macro_use]
+use pretty_assertions::{assert_eq, assert_ne};
+
+use crate::{Grid, GridErr, Line, Pos, Supercover};
+
+#[test]
+fn test_line() {
+    for (a, b) in [
+        ((0, 0), (5, 2)),
+        ((3, -4), (-2, 1)),
+        ((2, 2), (2, 2)),
+        ((7, 1), (0, 3)),
+    ] {
+        let line = Line::new(a, b).collect::<Vec<_>>();
+        assert_eq!(line.first(), Some(&Pos::from(a)));
+        assert_eq!(line.last(), Some(&Pos::from(b)));
+        assert!(line.windows(2).all(|w| w[0].chebyshev(w[1]) == 1));
+        let (a, b) = (Pos::from(a), Pos::from(b));
+        assert_eq!(line.len() as i32, a.chebyshev(b) + 1);
+    }
+
+    let line = Line::new((0, 4), (0, 0)).collect::<Vec<_>>();
+    assert_eq!(
+        line,
+        (0..=4)
+            .rev()
+            .map(|col| Pos::new(0, col))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_supercover() {
+    for (a, b) in [((0, 0), (5, 2)), ((3, -4), (-2, 1)), ((7, 1), (0, 3))] {
+        let line = Supercover::new(a, b).collect::<Vec<_>>();
+        assert_eq!(line.first(), Some(&Pos::from(a)));
+        assert_eq!(line.last(), Some(&Pos::from(b)));
+        assert!(line.windows(2).all(|w| w[0].chebyshev(w[1]) == 1));
+    }
+
+    let line = Supercover::new((0, 0), (2, 1)).collect::<Vec<_>>();
+    assert_eq!(
+        line,
+        vec![
+            Pos::new(0, 0),
+            Pos::new(1, 0),
+            Pos::new(1, 1),
+            Pos::new(2, 1)
+        ]
+    );
+    assert_eq!(Supercover::new((0, 0), (2, 2)).count(), 7);
+    assert_eq!(Supercover::new((1, 1), (1, 1)).count(), 1);
+}
+
+#[test]
+fn test_grid_line() {
+    let g = Grid::new_from_vector(
+        (5, 7),
+        (1., 1.),
+        "\
+        .......\
+        .......\
+        ...#...\
+        .......\
+        .......\
+        "
+        .chars()
+        .collect(),
+    );
+    let cells = g.line((2, -2), (2, 3)).collect::<Vec<_>>();
+    assert_eq!(
+        cells.iter().map(|(p, _)| p.col).collect::<Vec<_>>(),
+        vec![0, 1, 2, 3]
+    );
+    assert_eq!(cells.last(), Some(&(Pos::new(2, 3), &'#')));
+    assert_eq!(g.supercover_line((9, 9), (8, 8)).count(), 0);
+
+    assert_eq!(g.line_of_sight((2, 0), (2, 6), |c| *c == '#'), Ok(false));
+    assert_eq!(g.line_of_sight((2, 0), (2, 3), |c| *c == '#'), Ok(true));
+    assert_eq!(g.line_of_sight((0, 0), (4, 6), |c| *c == '#'), Ok(false));
+    assert_eq!(g.line_of_sight((0, 0), (1, 6), |c| *c == '#'), Ok(true));
+    assert_eq!(
+        g.line_of_sight((0, 0), (5, 0), |c| *c == '#'),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(5, 0)
+        })
+    );
+}
+
+#[test]
+fn test_fov() {
+    let g = Grid::new_from_vector(
+        (5, 7),
+        (1., 1.),
+        "\
+        .......\
+        .......\
+        ...#...\
+        .......\
+        .......\
+        "
+        .chars()
+        .collect(),
+    );
+    let visible = g.fov((2, 0), 10, |c| *c == '#').unwrap();
+    assert_eq!(
+        visible
+            .get_flatten_grid()
+            .into_iter()
+            .map(|v| if v { 'x' } else { '.' })
+            .collect::<String>(),
+        "\
+        xxxxxxx\
+        xxxxxxx\
+        xxxx...\
+        xxxxxxx\
+        xxxxxxx\
+        "
+    );
+
+    let visible = g.fov((2, 3), 2, |c| *c == '#').unwrap();
+    assert_eq!(
+        visible
+            .get_flatten_grid()
+            .into_iter()
+            .map(|v| if v { 'x' } else { '.' })
+            .collect::<String>(),
+        "\
+        ...x...\
+        ..xxx..\
+        .xxxxx.\
+        ..xxx..\
+        ...x...\
+        "
+    );
+
+    assert_eq!(
+        g.fov((5, 0), 3, |c| *c == '#').err(),
+        Some(GridErr::OutOfGrid {
+            pos: Pos::new(5, 0)
+        })
+    );
+}
+
+#[test]
+fn test_fov_symmetry() {
+    let g = Grid::new_from_vector(
+        (6, 6),
+        (1., 1.),
+        "\
+        ..#...\
+        ....#.\
+        .#....\
+        ...#..\
+        #.....\
+        ..#..#\
+        "
+        .chars()
+        .collect(),
+    );
+    let opaque = |c: &char| *c == '#';
+
+    let floors = g
+        .positions()
+        .filter(|p| !opaque(&g[*p]))
+        .collect::<Vec<_>>();
+    for a in &floors {
+        let from_a = g.fov(*a, 20, opaque).unwrap();
+        for b in &floors {
+            let from_b = g.fov(*b, 20, opaque).unwrap();
+            assert_eq!(from_a[*b], from_b[*a], "{} and {}", a, b);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{Grid, GridErr, Pos, Transform};
+
+/// Represents how the edges of the grid are connected
+///
+/// Topology::Bounded has no connection, the positions out of the grid are GridErr::OutOfGrid
+///
+/// Topology::WrapX wraps the cols, leaving the right edge enters on the left edge,
+/// like the tunnels of Pac-Man
+///
+/// Topology::WrapY wraps the rows, leaving the bottom edge enters on the top edge
+///
+/// Topology::Torus wraps the rows and the cols, like the map of Asteroids
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Topology {
+    #[default]
+    Bounded,
+    WrapX,
+    WrapY,
+    Torus,
+}
+
+impl Topology {
+    /// Returns true if the cols wrap around
+    pub fn wraps_cols(&self) -> bool {
+        matches!(self, Topology::WrapX | Topology::Torus)
+    }
+
+    /// Returns true if the rows wrap around
+    pub fn wraps_rows(&self) -> bool {
+        matches!(self, Topology::WrapY | Topology::Torus)
+    }
+
+    // Wraps the axes of the topology on a grid of the given size,
+    // the bounded axes are left as they are
+    pub(crate) fn wrapped(&self, pos: Pos, (rows, cols): (i32, i32)) -> Pos {
+        let row = if self.wraps_rows() {
+            pos.row.rem_euclid(rows)
+        } else {
+            pos.row
+        };
+        let col = if self.wraps_cols() {
+            pos.col.rem_euclid(cols)
+        } else {
+            pos.col
+        };
+        Pos::new(row, col)
+    }
+
+    // The topology of a grid after the transform, the wrapped axes follow the rows and cols
+    pub(crate) fn transformed(&self, transform: Transform) -> Topology {
+        match self {
+            Topology::WrapX if transform.swaps_axes() => Topology::WrapY,
+            Topology::WrapY if transform.swaps_axes() => Topology::WrapX,
+            other => *other,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// The topology of the grid, Topology::Bounded unless changed by `Grid::set_topology`
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    /// Sets the topology of the grid
+    ///
+    /// The positions of the wrapped axes are never out of the grid, so `get`, `set`,
+    /// the moves, the neighbors and the paths go around the edges
+    ///
+    /// ```.rust
+    /// use das_grid::{MoveDirection, Topology};
+    ///
+    /// let mut grid = das_grid::Grid::new((3, 5), (1., 1.), 0);
+    /// grid.set_topology(Topology::WrapX);
+    /// grid.set((1, 4), 1).unwrap();
+    ///
+    /// // Leaving the right edge enters on the left edge
+    /// assert_eq!(grid.mov_to((1, 4), MoveDirection::Right), Ok(0));
+    /// assert_eq!(grid.get((1, 0)), Ok(&1));
+    /// assert_eq!(grid.get((1, -5)), Ok(&1));
+    ///
+    /// // The rows are still bounded
+    /// assert!(grid.mov_to((1, 0), MoveDirection::Up).is_ok());
+    /// assert!(grid.mov_to((0, 0), MoveDirection::Up).is_err());
+    /// ```
+    pub fn set_topology(&mut self, topology: Topology) {
+        self.topology = topology;
+    }
+
+    /// Returns the position inside of the grid, wrapping the axes of the topology
+    ///
+    /// If the position is out of a bounded axis it return an error of GridErr::OutOfGrid
+    ///
+    /// ```.rust
+    /// use das_grid::{Pos, Topology};
+    ///
+    /// let mut grid = das_grid::Grid::new((3, 3), (1., 1.), 0);
+    /// assert_eq!(grid.wrap((1, 3)), Err(das_grid::GridErr::OutOfGrid { pos: Pos::new(1, 3) }));
+    ///
+    /// grid.set_topology(Topology::Torus);
+    /// assert_eq!(grid.wrap((-1, 3)), Ok(Pos::new(2, 0)));
+    /// ```
+    pub fn wrap(&self, pos: impl Into<Pos>) -> Result<Pos, GridErr> {
+        let pos = pos.into();
+        self.check_grid_bounds(pos)?;
+        Ok(self.wrapped(pos))
+    }
+
+    // Wraps the axes of the topology, the bounded axes are left as they are
+    pub(crate) fn wrapped(&self, pos: Pos) -> Pos {
+        self.topology.wrapped(pos, (self.rows, self.cols))
+    }
+}
@@ -0,0 +1,174 @@
+#![allow(warnings, unused)]
+#[macro_use]
+use pretty_assertions::{assert_eq, assert_ne};
+
+use crate::{Connectivity, Edges, Grid, GridErr, MoveDirection, Pos, Topology, Transform};
+
+#[test]
+fn test_wrap_x_tunnel() {
+    let mut g = Grid::new((3, 4), (1., 1.), '.');
+    g.set_topology(Topology::WrapX);
+    assert_eq!(g.topology(), Topology::WrapX);
+
+    g.set((1, 0), 'P');
+    assert_eq!(g.mov_to((1, 0), MoveDirection::Left), Ok('.'));
+    assert_eq!(g.get((1, 3)), Ok(&'P'));
+    assert_eq!(g.get((1, -1)), Ok(&'P'));
+    assert_eq!(g.get((1, 7)), Ok(&'P'));
+    assert_eq!(g.mov_by((1, 3), (0, 6)), Ok('.'));
+    assert_eq!(g.get((1, 1)), Ok(&'P'));
+
+    // The rows are bounded
+    assert_eq!(g.mov_to((1, 1), MoveDirection::UpRight), Ok('.'),);
+    assert_eq!(
+        g.mov_to((0, 2), MoveDirection::Up),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(-1, 2)
+        })
+    );
+    assert_eq!(
+        g.set((3, 0), 'x'),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(3, 0)
+        })
+    );
+}
+
+#[test]
+fn test_wrap_y_and_bounded() {
+    let mut g = Grid::new((3, 3), (1., 1.), 0);
+    assert_eq!(
+        g.wrap((0, 3)),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(0, 3)
+        })
+    );
+
+    g.set_topology(Topology::WrapY);
+    assert_eq!(g.wrap((-1, 2)), Ok(Pos::new(2, 2)));
+    assert_eq!(g.wrap((5, 1)), Ok(Pos::new(2, 1)));
+    assert_eq!(
+        g.wrap((0, 3)),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(0, 3)
+        })
+    );
+
+    g.set_topology(Topology::Bounded);
+    assert_eq!(
+        g.get((-1, 2)),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(-1, 2)
+        })
+    );
+}
+
+#[test]
+fn test_torus_neighbors() {
+    let mut g = Grid::new_from_vector((3, 4), (1., 1.), (0..12).collect());
+    g.set_topology(Topology::Torus);
+
+    let mut neighbors = g
+        .neighbors8((0, 0))
+        .map(|(p, v)| (p, *v))
+        .collect::<Vec<_>>();
+    neighbors.sort();
+    assert_eq!(
+        neighbors,
+        vec![
+            (Pos::new(0, 1), 1),
+            (Pos::new(0, 3), 3),
+            (Pos::new(1, 0), 4),
+            (Pos::new(1, 1), 5),
+            (Pos::new(1, 3), 7),
+            (Pos::new(2, 0), 8),
+            (Pos::new(2, 1), 9),
+            (Pos::new(2, 3), 11),
+        ]
+    );
+    assert_eq!(g.neighbors4((2, 3)).count(), 4);
+
+    // The automaton neighbors stop on the edges only on a bounded grid
+    let mut counts = Grid::new((3, 3), (1., 1.), 0);
+    counts.set_topology(Topology::Torus);
+    counts.step(Edges::Clamp, |_, n| n.count());
+    assert_eq!(counts.get_flatten_grid(), vec![8; 9]);
+}
+
+#[test]
+fn test_torus_paths_and_regions() {
+    let mut g = Grid::new_from_vector(
+        (3, 6),
+        (1., 1.),
+        "\
+        .#..#.\
+        .#..#.\
+        .#..#.\
+        "
+        .chars()
+        .collect(),
+    );
+    assert_eq!(
+        g.bfs_path((1, 0), (1, 5), Connectivity::Four, |c| *c == '.'),
+        None
+    );
+
+    g.set_topology(Topology::WrapX);
+    let path = g
+        .bfs_path((1, 0), (1, 5), Connectivity::Four, |c| *c == '.')
+        .unwrap();
+    assert_eq!(path.positions, vec![Pos::new(1, 0), Pos::new(1, 5)]);
+
+    // The positions out of the grid are wrapped before searching
+    let path = g
+        .bfs_path((1, 6), (1, -1), Connectivity::Four, |c| *c == '.')
+        .unwrap();
+    assert_eq!(path.positions, vec![Pos::new(1, 0), Pos::new(1, 5)]);
+
+    let range = g
+        .movement_range((0, -1), 1, Connectivity::Four, |c| (*c == '.').then_some(1))
+        .unwrap();
+    assert_eq!(range.cost((0, 0)), Some(1));
+    assert_eq!(range.cost((2, 5)), None);
+
+    assert_eq!(
+        g.flood_fill((0, 6), 'o', |c| *c == '.', Connectivity::Four),
+        Ok(6)
+    );
+    assert_eq!(g.get_col(5).unwrap(), vec!['o', 'o', 'o']);
+    assert_eq!(g.get_col(2).unwrap(), vec!['.', '.', '.']);
+}
+
+#[test]
+fn test_transform_swaps_wrapped_axis() {
+    let mut g = Grid::new((2, 3), (1., 1.), 0);
+    g.set_topology(Topology::WrapX);
+    assert_eq!(
+        g.transformed(Transform::Rotate90Cw).topology(),
+        Topology::WrapY
+    );
+    assert_eq!(
+        g.transformed(Transform::Rotate180).topology(),
+        Topology::WrapX
+    );
+
+    g.transpose();
+    assert_eq!(g.topology(), Topology::WrapY);
+    g.set_topology(Topology::Torus);
+    g.rotate90_ccw();
+    assert_eq!(g.topology(), Topology::Torus);
+}
+
+#[test]
+fn test_torus_fov_unlimited_radius() {
+    // The scan stops after a lap around the grid instead of going on up to the radius
+    let mut g = Grid::new_from_vector((3, 4), (1., 1.), "....#.......".chars().collect());
+    g.set_topology(Topology::Torus);
+    let visible = g.fov((0, 0), i32::MAX, |c| *c == '#').unwrap();
+    assert!((&visible).into_iter().all(|v| *v));
+
+    let mut g = Grid::new((3, 3), (1., 1.), 0);
+    g.set_topology(Topology::WrapX);
+    let visible = g.fov((1, 1), 3000, |v| *v == 1).unwrap();
+    assert!((&visible).into_iter().all(|v| *v));
+}
@@ -0,0 +1,401 @@
+use crate::{neighbors, pathfinding, pattern, region, sight};
+use crate::{
+    Connectivity, DistanceMetric, Grid, GridErr, Line, MovementRange, Offset, Path, PatternMatch,
+    Pos, Region, Run, Supercover, Topology, Transform,
+};
+
+/// Represents a grid that can be read, whatever the storage of its cells
+///
+/// The algorithms like neighbors, pathfinding, line of sight and patterns are written once
+/// over this trait, so they run on any grid that implements it, a storage only needs
+/// `dimensions` and `get`
+///
+/// `Grid` has the same methods without importing the trait
+///
+/// ```.rust
+/// use das_grid::{GridErr, GridRead, Pos};
+///
+/// // A grid of numbers stored on a plain vector
+/// struct Numbers(Vec<i32>);
+///
+/// impl GridRead for Numbers {
+///     type Item = i32;
+///
+///     fn dimensions(&self) -> (i32, i32) {
+///         (1, self.0.len() as i32)
+///     }
+///
+///     fn get(&self, pos: impl Into<Pos>) -> Result<&i32, GridErr> {
+///         let pos = self.wrap(pos)?;
+///         Ok(&self.0[pos.col as usize])
+///     }
+/// }
+///
+/// let numbers = Numbers(vec![1, 2, 3]);
+/// assert!(numbers.in_bounds((0, 2)));
+/// assert_eq!(numbers.neighbors4((0, 1)).map(|(_, v)| *v).sum::<i32>(), 4);
+/// ```
+pub trait GridRead {
+    /// The type of the values of the cells
+    type Item;
+
+    /// The size (rows, cols) of the grid
+    fn dimensions(&self) -> (i32, i32);
+
+    /// Gets the value of the position (row, col)
+    ///
+    /// If the position is out of the grid it return an error of GridErr::OutOfGrid
+    fn get(&self, pos: impl Into<Pos>) -> Result<&Self::Item, GridErr>;
+
+    /// Returns the position inside of the grid, the grids that wrap their edges
+    /// return the position on the other side
+    ///
+    /// If the position is out of the grid it return an error of GridErr::OutOfGrid
+    fn wrap(&self, pos: impl Into<Pos>) -> Result<Pos, GridErr> {
+        let pos = pos.into();
+        let (rows, cols) = self.dimensions();
+        if pos.row < 0 || pos.row >= rows || pos.col < 0 || pos.col >= cols {
+            return Err(GridErr::OutOfGrid { pos });
+        }
+
+        Ok(pos)
+    }
+
+    /// Checks if the position is inside of the grid
+    fn in_bounds(&self, pos: impl Into<Pos>) -> bool {
+        self.wrap(pos).is_ok()
+    }
+
+    /// Returns the positions and values of every cell, row by row
+    ///
+    /// ```.rust
+    /// use das_grid::GridRead;
+    ///
+    /// let grid = das_grid::Grid::new_from_vector((2, 2), (1., 1.), vec![1, 2, 3, 4]);
+    /// let (pos, value) = grid.iter().last().unwrap();
+    /// assert_eq!((pos, value), ((1, 1).into(), &4));
+    /// ```
+    fn iter(&self) -> impl Iterator<Item = (Pos, &Self::Item)> {
+        let (rows, cols) = self.dimensions();
+        (0..rows)
+            .flat_map(move |row| (0..cols).map(move |col| Pos::new(row, col)))
+            .filter_map(move |pos| self.get(pos).ok().map(|value| (pos, value)))
+    }
+
+    /// Returns the orthogonal neighbors (von Neumann neighborhood) of the position
+    /// with their values, see `Grid::neighbors4`
+    fn neighbors4(&self, pos: impl Into<Pos>) -> impl Iterator<Item = (Pos, &Self::Item)> {
+        neighbors::cells_at(self, pos.into().neighbors4())
+    }
+
+    /// Returns the orthogonal and diagonal neighbors (Moore neighborhood) of the position
+    /// with their values, see `Grid::neighbors8`
+    fn neighbors8(&self, pos: impl Into<Pos>) -> impl Iterator<Item = (Pos, &Self::Item)> {
+        neighbors::cells_at(self, pos.into().neighbors8())
+    }
+
+    /// Returns every cell inside of the radius of the position, measured with the given metric,
+    /// see `Grid::neighbors_in_radius`
+    fn neighbors_in_radius(
+        &self,
+        pos: impl Into<Pos>,
+        radius: i32,
+        metric: DistanceMetric,
+    ) -> impl Iterator<Item = (Pos, &Self::Item)> {
+        neighbors::cells_at(self, neighbors::radius(pos.into(), radius, metric))
+    }
+
+    /// Returns the cells at each offset of the stencil from the position,
+    /// see `Grid::neighbors_with`
+    fn neighbors_with<'a>(
+        &'a self,
+        pos: impl Into<Pos>,
+        offsets: &'a [Offset],
+    ) -> impl Iterator<Item = (Pos, &'a Self::Item)> + 'a {
+        neighbors::Stencil::new(self, pos.into(), offsets)
+    }
+
+    /// Finds the path with the fewest steps between two positions using breadth first search,
+    /// see `Grid::bfs_path`
+    fn bfs_path<F>(
+        &self,
+        src: impl Into<Pos>,
+        dst: impl Into<Pos>,
+        connectivity: Connectivity,
+        passable: F,
+    ) -> Option<Path>
+    where
+        F: Fn(&Self::Item) -> bool,
+    {
+        let (src, dst) = (self.wrap(src).ok()?, self.wrap(dst).ok()?);
+        pathfinding::bfs(self, src, dst, connectivity, &passable)
+    }
+
+    /// Finds the cheapest path between two positions using Dijkstra, see `Grid::dijkstra_path`
+    fn dijkstra_path<F>(
+        &self,
+        src: impl Into<Pos>,
+        dst: impl Into<Pos>,
+        connectivity: Connectivity,
+        cost: F,
+    ) -> Option<Path>
+    where
+        F: Fn(&Self::Item) -> Option<u32>,
+    {
+        self.astar_path(src, dst, connectivity, cost, |_, _| 0)
+    }
+
+    /// Finds the cheapest path between two positions using A*, see `Grid::astar_path`
+    fn astar_path<F, H>(
+        &self,
+        src: impl Into<Pos>,
+        dst: impl Into<Pos>,
+        connectivity: Connectivity,
+        cost: F,
+        heuristic: H,
+    ) -> Option<Path>
+    where
+        F: Fn(&Self::Item) -> Option<u32>,
+        H: Fn(Pos, Pos) -> u32,
+    {
+        let (src, dst) = (self.wrap(src).ok()?, self.wrap(dst).ok()?);
+        pathfinding::astar(self, src, dst, connectivity, &cost, &heuristic)
+    }
+
+    /// Finds every cell reachable from the origin spending at most the given points,
+    /// see `Grid::movement_range`
+    fn movement_range<F>(
+        &self,
+        src: impl Into<Pos>,
+        points: u32,
+        connectivity: Connectivity,
+        cost: F,
+    ) -> Result<MovementRange, GridErr>
+    where
+        F: Fn(&Self::Item) -> Option<u32>,
+    {
+        let (costs, prev) =
+            pathfinding::movement_range(self, src.into(), points, connectivity, &cost)?;
+        Ok(MovementRange {
+            costs: sized_like(self, costs),
+            prev: sized_like(self, prev),
+        })
+    }
+
+    /// Labels the regions of connected cells, see `Grid::connected_components`
+    fn connected_components<E>(
+        &self,
+        eq: E,
+        connectivity: Connectivity,
+    ) -> (Grid<usize>, Vec<Region>)
+    where
+        E: Fn(&Self::Item, &Self::Item) -> bool,
+    {
+        let (labels, regions) = region::connected_components(self, &eq, connectivity);
+        (sized_like(self, labels), regions)
+    }
+
+    /// Returns the cells on the Bresenham line between two positions with their values,
+    /// see `Grid::line`
+    fn line(
+        &self,
+        a: impl Into<Pos>,
+        b: impl Into<Pos>,
+    ) -> impl Iterator<Item = (Pos, &Self::Item)> {
+        neighbors::cells_at(self, Line::new(a, b))
+    }
+
+    /// Returns every cell touched by the segment between two positions with their values,
+    /// see `Grid::supercover_line`
+    fn supercover_line(
+        &self,
+        a: impl Into<Pos>,
+        b: impl Into<Pos>,
+    ) -> impl Iterator<Item = (Pos, &Self::Item)> {
+        neighbors::cells_at(self, Supercover::new(a, b))
+    }
+
+    /// Checks if there is no opaque cell on the Bresenham line between two positions,
+    /// see `Grid::line_of_sight`
+    fn line_of_sight<F>(
+        &self,
+        a: impl Into<Pos>,
+        b: impl Into<Pos>,
+        opaque: F,
+    ) -> Result<bool, GridErr>
+    where
+        F: Fn(&Self::Item) -> bool,
+    {
+        sight::line_of_sight(self, a.into(), b.into(), &opaque)
+    }
+
+    /// Computes which cells can be seen from the origin using symmetric shadowcasting,
+    /// see `Grid::fov`
+    fn fov<F>(&self, origin: impl Into<Pos>, radius: i32, opaque: F) -> Result<Grid<bool>, GridErr>
+    where
+        F: Fn(&Self::Item) -> bool,
+    {
+        let visible = sight::fov(self, origin.into(), radius, &opaque)?;
+        Ok(sized_like(self, visible))
+    }
+
+    /// Finds the horizontal, vertical and diagonal runs of at least min_len cells
+    /// where each cell is equal to the previous one, see `Grid::find_runs`
+    fn find_runs<F>(&self, min_len: usize, eq: F) -> Vec<Run>
+    where
+        F: Fn(&Self::Item, &Self::Item) -> bool,
+    {
+        pattern::scan_runs(self, min_len, &|_| true, &eq)
+    }
+
+    /// Finds the horizontal, vertical and diagonal runs of at least min_len cells
+    /// where every cell matches the predicate, see `Grid::find_runs_by`
+    fn find_runs_by<F>(&self, min_len: usize, pred: F) -> Vec<Run>
+    where
+        F: Fn(&Self::Item) -> bool,
+    {
+        pattern::scan_runs(self, min_len, &pred, &|_, b| pred(b))
+    }
+
+    /// Finds the runs of at least min_len equal cells and merges the runs that share a cell,
+    /// see `Grid::find_matches`
+    fn find_matches<F>(&self, min_len: usize, eq: F) -> Vec<Vec<Pos>>
+    where
+        F: Fn(&Self::Item, &Self::Item) -> bool,
+    {
+        pattern::merge_runs(self.find_runs(min_len, eq))
+    }
+
+    /// Finds every place where the pattern fits, see `Grid::find_pattern`
+    fn find_pattern(
+        &self,
+        pattern: &Grid<Option<Self::Item>>,
+        transforms: &[Transform],
+    ) -> Vec<PatternMatch>
+    where
+        Self::Item: PartialEq,
+    {
+        pattern::find_pattern(self, pattern, transforms)
+    }
+}
+
+/// Represents a grid whose cells can be changed, whatever the storage of its cells
+///
+/// `Grid` has the same methods without importing the trait
+pub trait GridWrite: GridRead {
+    /// Gets the value of the position (row, col) as mutable
+    ///
+    /// If the position is out of the grid it return an error of GridErr::OutOfGrid
+    fn get_mut(&mut self, pos: impl Into<Pos>) -> Result<&mut Self::Item, GridErr>;
+
+    /// Sets a given value to the position (row, col)
+    ///
+    /// If the position is out of the grid it return an error of GridErr::OutOfGrid
+    fn set(&mut self, pos: impl Into<Pos>, value: Self::Item) -> Result<(), GridErr> {
+        *self.get_mut(pos)? = value;
+        Ok(())
+    }
+
+    /// Replaces the value of every cell connected to the position that matches the predicate,
+    /// see `Grid::flood_fill`
+    fn flood_fill<P>(
+        &mut self,
+        pos: impl Into<Pos>,
+        new_value: Self::Item,
+        predicate: P,
+        connectivity: Connectivity,
+    ) -> Result<usize, GridErr>
+    where
+        Self::Item: Clone,
+        P: Fn(&Self::Item) -> bool,
+    {
+        let pos = self.wrap(pos)?;
+        region::flood_fill(self, pos, new_value, &predicate, connectivity)
+    }
+}
+
+impl<T> GridRead for Grid<T> {
+    type Item = T;
+
+    fn dimensions(&self) -> (i32, i32) {
+        (self.rows, self.cols)
+    }
+
+    fn get(&self, pos: impl Into<Pos>) -> Result<&T, GridErr> {
+        Grid::get(self, pos)
+    }
+
+    fn wrap(&self, pos: impl Into<Pos>) -> Result<Pos, GridErr> {
+        Grid::wrap(self, pos)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    // The grids returned keep the cell size and the topology of the grid
+    fn movement_range<F>(
+        &self,
+        src: impl Into<Pos>,
+        points: u32,
+        connectivity: Connectivity,
+        cost: F,
+    ) -> Result<MovementRange, GridErr>
+    where
+        F: Fn(&T) -> Option<u32>,
+    {
+        let (costs, prev) =
+            pathfinding::movement_range(self, src.into(), points, connectivity, &cost)?;
+        Ok(MovementRange {
+            costs: self.with_cells(costs),
+            prev: self.with_cells(prev),
+        })
+    }
+
+    fn connected_components<E>(
+        &self,
+        eq: E,
+        connectivity: Connectivity,
+    ) -> (Grid<usize>, Vec<Region>)
+    where
+        E: Fn(&T, &T) -> bool,
+    {
+        let (labels, regions) = region::connected_components(self, &eq, connectivity);
+        (self.with_cells(labels), regions)
+    }
+
+    fn fov<F>(&self, origin: impl Into<Pos>, radius: i32, opaque: F) -> Result<Grid<bool>, GridErr>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let visible = sight::fov(self, origin.into(), radius, &opaque)?;
+        Ok(self.with_cells(visible))
+    }
+}
+
+impl<T> GridWrite for Grid<T> {
+    fn get_mut(&mut self, pos: impl Into<Pos>) -> Result<&mut T, GridErr> {
+        Grid::get_mut(self, pos)
+    }
+
+    fn set(&mut self, pos: impl Into<Pos>, value: T) -> Result<(), GridErr> {
+        Grid::set(self, pos, value)
+    }
+}
+
+// A bounded grid of the same size holding the given cells, row by row
+fn sized_like<G, U>(grid: &G, cells: Vec<U>) -> Grid<U>
+where
+    G: GridRead + ?Sized,
+    U: Default,
+{
+    let (rows, cols) = grid.dimensions();
+    Grid {
+        rows,
+        cols,
+        cell_size: (1., 1.),
+        initial_value: U::default(),
+        topology: Topology::Bounded,
+        cells,
+    }
+}
@@ -0,0 +1,175 @@
+#![allow(warnings, unused)]
+#[macro_use]
+use pretty_assertions::{assert_eq, assert_ne};
+
+use std::collections::HashMap;
+
+use crate::{Connectivity, Grid, GridErr, GridRead, GridWrite, Pos, Topology, Transform};
+
+// A sparse storage, the missing cells are walls
+struct Sparse {
+    rows: i32,
+    cols: i32,
+    wall: char,
+    cells: HashMap<Pos, char>,
+}
+
+impl Sparse {
+    fn new(rows: i32, cols: i32, open: &[(i32, i32)]) -> Self {
+        let cells = open.iter().map(|p| (Pos::from(*p), '.')).collect();
+        Self {
+            rows,
+            cols,
+            wall: '#',
+            cells,
+        }
+    }
+}
+
+impl GridRead for Sparse {
+    type Item = char;
+
+    fn dimensions(&self) -> (i32, i32) {
+        (self.rows, self.cols)
+    }
+
+    fn get(&self, pos: impl Into<Pos>) -> Result<&char, GridErr> {
+        let pos = self.wrap(pos)?;
+        Ok(self.cells.get(&pos).unwrap_or(&self.wall))
+    }
+}
+
+impl GridWrite for Sparse {
+    fn get_mut(&mut self, pos: impl Into<Pos>) -> Result<&mut char, GridErr> {
+        let pos = self.wrap(pos)?;
+        Ok(self.cells.entry(pos).or_insert(self.wall))
+    }
+}
+
+// Written once, runs on any storage
+fn count_open<G: GridRead<Item = char>>(grid: &G) -> usize {
+    grid.iter().filter(|(_, c)| **c == '.').count()
+}
+
+#[test]
+fn test_grid_read_on_grid() {
+    let mut g = Grid::new_from_vector((2, 3), (1., 1.), "..#.#.".chars().collect());
+    assert_eq!(GridRead::dimensions(&g), (2, 3));
+    assert!(g.in_bounds((1, 2)));
+    assert!(!g.in_bounds((2, 0)));
+    assert_eq!(count_open(&g), 4);
+    assert_eq!(g.iter().nth(4), Some((Pos::new(1, 1), &'#')));
+
+    // The topology of the grid is kept by the trait
+    g.set_topology(Topology::WrapX);
+    assert!(g.in_bounds((1, -1)));
+    assert_eq!(GridRead::wrap(&g, (1, -1)), Ok(Pos::new(1, 2)));
+
+    GridWrite::set(&mut g, (0, 2), '.').unwrap();
+    assert_eq!(count_open(&g), 5);
+}
+
+#[test]
+fn test_grid_read_default_methods() {
+    let s = Sparse::new(3, 3, &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]);
+    assert!(s.in_bounds((2, 2)));
+    assert!(!s.in_bounds((-1, 0)));
+    assert_eq!(
+        s.get((3, 0)),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(3, 0)
+        })
+    );
+    assert_eq!(count_open(&s), 5);
+    assert_eq!(s.iter().count(), 9);
+    assert_eq!(s.iter().next(), Some((Pos::new(0, 0), &'.')));
+
+    let open = s.neighbors8((1, 1)).filter(|(_, c)| **c == '.').count();
+    assert_eq!(open, 5);
+    assert_eq!(s.neighbors4((0, 0)).count(), 2);
+}
+
+#[test]
+fn test_grid_read_pathfinding() {
+    let s = Sparse::new(3, 3, &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]);
+    let path = s
+        .bfs_path((0, 0), (2, 2), Connectivity::Four, |c| *c == '.')
+        .unwrap();
+    assert_eq!(path.cost, 4);
+    assert_eq!(path.positions[2], Pos::new(2, 0));
+
+    let path = s
+        .dijkstra_path((0, 0), (2, 2), Connectivity::Four, |c| {
+            (*c == '.').then_some(2)
+        })
+        .unwrap();
+    assert_eq!(path.cost, 8);
+
+    assert_eq!(
+        s.bfs_path((0, 0), (0, 2), Connectivity::Four, |c| *c == '.'),
+        None
+    );
+    assert_eq!(
+        s.bfs_path((0, 0), (5, 5), Connectivity::Four, |c| *c == '.'),
+        None
+    );
+}
+
+#[test]
+fn test_grid_write_flood_fill() {
+    let mut s = Sparse::new(3, 3, &[(0, 0), (1, 0), (2, 0), (2, 1), (0, 2)]);
+    assert_eq!(
+        s.flood_fill((2, 1), '~', |c| *c == '.', Connectivity::Four),
+        Ok(4)
+    );
+    assert_eq!(s.get((0, 0)), Ok(&'~'));
+    assert_eq!(s.get((0, 2)), Ok(&'.'));
+    assert_eq!(
+        s.flood_fill((3, 3), '~', |c| *c == '.', Connectivity::Four),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(3, 3)
+        })
+    );
+
+    s.set((1, 1), '.').unwrap();
+    assert_eq!(count_open(&s), 2);
+}
+
+#[test]
+fn test_grid_read_regions_and_sight() {
+    let s = Sparse::new(3, 3, &[(0, 0), (1, 0), (2, 0), (2, 1), (0, 2)]);
+    let (labels, regions) = s.connected_components(|a, b| a == b, Connectivity::Four);
+    assert_eq!(regions.len(), 3);
+    assert_eq!(labels.get((2, 1)), labels.get((0, 0)));
+    assert_ne!(labels.get((0, 2)), labels.get((0, 0)));
+
+    let range = s
+        .movement_range((0, 0), 2, Connectivity::Four, |c| (*c == '.').then_some(1))
+        .unwrap();
+    assert_eq!(range.cost((2, 0)), Some(2));
+    assert_eq!(range.cost((2, 1)), None);
+
+    assert_eq!(s.line_of_sight((0, 0), (2, 0), |c| *c == '#'), Ok(true));
+    assert_eq!(s.line_of_sight((0, 0), (0, 2), |c| *c == '#'), Ok(false));
+    let visible = s.fov((2, 0), 5, |c| *c == '#').unwrap();
+    assert_eq!(visible.get((0, 0)), Ok(&true));
+    assert_eq!(visible.get((1, 1)), Ok(&true));
+    assert_eq!(visible.get((0, 2)), Ok(&false));
+}
+
+#[test]
+fn test_grid_read_patterns() {
+    let s = Sparse::new(3, 3, &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]);
+    let runs = s.find_runs_by(3, |c| *c == '.');
+    assert_eq!(runs.len(), 2);
+    assert_eq!(s.find_matches(3, |a, b| a == b && *a == '.').len(), 1);
+
+    let corner = Grid::new_from_vector(
+        (2, 2),
+        (1., 1.),
+        vec![Some('.'), None, Some('.'), Some('.')],
+    );
+    let found = s.find_pattern(&corner, &[Transform::Identity]);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].pos, Pos::new(1, 0));
+}
@@ -0,0 +1,225 @@
+use crate::{Grid, Pos};
+
+/// Represents a whole grid transformation
+///
+/// Transform::Rotate90Cw, Transform::Rotate90Ccw and Transform::Transpose swap
+/// the rows and the cols of the grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transform {
+    Identity,
+    Rotate90Cw,
+    Rotate90Ccw,
+    Rotate180,
+    Transpose,
+    FlipHorizontal,
+    FlipVertical,
+}
+
+impl Transform {
+    /// The four rotations, starting with the identity and going clockwise
+    pub const ROTATIONS: [Transform; 4] = [
+        Transform::Identity,
+        Transform::Rotate90Cw,
+        Transform::Rotate180,
+        Transform::Rotate90Ccw,
+    ];
+
+    /// Returns true if the transform swaps the rows and the cols
+    pub fn swaps_axes(&self) -> bool {
+        matches!(
+            self,
+            Transform::Rotate90Cw | Transform::Rotate90Ccw | Transform::Transpose
+        )
+    }
+
+    /// Where the position of a grid of the given size goes after the transform
+    ///
+    /// ```.rust
+    /// use das_grid::{Pos, Transform};
+    ///
+    /// // The top left corner of a 2x3 grid goes to the top right of the rotated 3x2 grid
+    /// assert_eq!(Transform::Rotate90Cw.apply(Pos::new(0, 0), (2, 3)), Pos::new(0, 1));
+    /// ```
+    pub fn apply(&self, pos: Pos, frame_size: (i32, i32)) -> Pos {
+        let (rows, cols) = frame_size;
+        match self {
+            Transform::Identity => pos,
+            Transform::Rotate90Cw => Pos::new(pos.col, rows - 1 - pos.row),
+            Transform::Rotate90Ccw => Pos::new(cols - 1 - pos.col, pos.row),
+            Transform::Rotate180 => Pos::new(rows - 1 - pos.row, cols - 1 - pos.col),
+            Transform::Transpose => Pos::new(pos.col, pos.row),
+            Transform::FlipHorizontal => Pos::new(pos.row, cols - 1 - pos.col),
+            Transform::FlipVertical => Pos::new(rows - 1 - pos.row, pos.col),
+        }
+    }
+
+    /// The transform that undoes this one
+    pub fn inverse(&self) -> Transform {
+        match self {
+            Transform::Rotate90Cw => Transform::Rotate90Ccw,
+            Transform::Rotate90Ccw => Transform::Rotate90Cw,
+            other => *other,
+        }
+    }
+
+    // The size of a grid of the given size after the transform
+    pub(crate) fn frame_size(&self, frame_size: (i32, i32)) -> (i32, i32) {
+        if self.swaps_axes() {
+            (frame_size.1, frame_size.0)
+        } else {
+            frame_size
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Applies the transform to the grid, the rows, the cols, the cell size
+    /// and the wrapping of the topology are swapped when the transform swaps the axes
+    ///
+    /// ```.rust
+    /// use das_grid::Transform;
+    ///
+    /// let mut grid = das_grid::Grid::new_from_vector((2, 3), (1., 2.), vec![1, 2, 3, 4, 5, 6]);
+    /// grid.transform(Transform::Rotate90Cw);
+    /// assert_eq!((grid.rows(), grid.cols()), (3, 2));
+    /// assert_eq!(grid.get_cell_size(), (2., 1.));
+    /// assert_eq!(grid.get_flatten_grid(), vec![4, 1, 5, 2, 6, 3]);
+    /// ```
+    pub fn transform(&mut self, transform: Transform) {
+        let frame_size = (self.rows, self.cols);
+        let (rows, cols) = transform.frame_size(frame_size);
+
+        // Every new cell takes the value of the old position that lands on it
+        let mut cells = vec![];
+        cells.resize_with(self.size(), || None);
+        for (pos, value) in self.positions().zip(std::mem::take(&mut self.cells)) {
+            let dst = transform.apply(pos, frame_size);
+            cells[(dst.row * cols + dst.col) as usize] = Some(value);
+        }
+
+        self.cells = cells.into_iter().flatten().collect();
+        self.rows = rows;
+        self.cols = cols;
+        self.topology = self.topology.transformed(transform);
+        if transform.swaps_axes() {
+            self.cell_size = (self.cell_size.1, self.cell_size.0);
+        }
+    }
+
+    /// Returns a copy of the grid with the transform applied
+    ///
+    /// ```.rust
+    /// use das_grid::Transform;
+    ///
+    /// let grid = das_grid::Grid::new_from_vector((2, 2), (1., 1.), vec![1, 2, 3, 4]);
+    /// let flipped = grid.transformed(Transform::FlipVertical);
+    /// assert_eq!(flipped.get_flatten_grid(), vec![3, 4, 1, 2]);
+    /// assert_eq!(grid.get_flatten_grid(), vec![1, 2, 3, 4]);
+    /// ```
+    pub fn transformed(&self, transform: Transform) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let frame_size = (self.rows, self.cols);
+        let (rows, cols) = transform.frame_size(frame_size);
+        let inverse = transform.inverse();
+
+        let cells = (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| Pos::new(row, col)))
+            .map(|pos| self.cells[self.cell_index(inverse.apply(pos, (rows, cols)))].clone())
+            .collect();
+
+        let cell_size = if transform.swaps_axes() {
+            (self.cell_size.1, self.cell_size.0)
+        } else {
+            self.cell_size
+        };
+
+        Grid {
+            rows,
+            cols,
+            cell_size,
+            initial_value: self.initial_value.clone(),
+            topology: self.topology.transformed(transform),
+            cells,
+        }
+    }
+
+    /// Rotates the grid 90 degrees clockwise
+    pub fn rotate90_cw(&mut self) {
+        self.transform(Transform::Rotate90Cw);
+    }
+
+    /// Rotates the grid 90 degrees counter clockwise
+    pub fn rotate90_ccw(&mut self) {
+        self.transform(Transform::Rotate90Ccw);
+    }
+
+    /// Rotates the grid 180 degrees
+    pub fn rotate180(&mut self) {
+        self.transform(Transform::Rotate180);
+    }
+
+    /// Swaps the rows and the cols of the grid
+    pub fn transpose(&mut self) {
+        self.transform(Transform::Transpose);
+    }
+
+    /// Mirrors the grid from left to right
+    pub fn flip_horizontal(&mut self) {
+        self.transform(Transform::FlipHorizontal);
+    }
+
+    /// Mirrors the grid from top to bottom
+    pub fn flip_vertical(&mut self) {
+        self.transform(Transform::FlipVertical);
+    }
+
+    /// Returns a copy of the grid rotated 90 degrees clockwise
+    pub fn rotated90_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transformed(Transform::Rotate90Cw)
+    }
+
+    /// Returns a copy of the grid rotated 90 degrees counter clockwise
+    pub fn rotated90_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transformed(Transform::Rotate90Ccw)
+    }
+
+    /// Returns a copy of the grid rotated 180 degrees
+    pub fn rotated180(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transformed(Transform::Rotate180)
+    }
+
+    /// Returns a copy of the grid with the rows and the cols swapped
+    pub fn transposed(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transformed(Transform::Transpose)
+    }
+
+    /// Returns a copy of the grid mirrored from left to right
+    pub fn flipped_horizontal(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transformed(Transform::FlipHorizontal)
+    }
+
+    /// Returns a copy of the grid mirrored from top to bottom
+    pub fn flipped_vertical(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transformed(Transform::FlipVertical)
+    }
+}
@@ -0,0 +1,118 @@
+#![allow(warnings, unused)]
+#[macro_use]
+use pretty_assertions::{assert_eq, assert_ne};
+
+use crate::{Grid, Pos, Transform};
+
+#[test]
+fn test_rotations() {
+    // 1 2 3
+    // 4 5 6
+    let g = Grid::new_from_vector((2, 3), (1., 2.), vec![1, 2, 3, 4, 5, 6]);
+
+    let cw = g.rotated90_cw();
+    assert_eq!((cw.rows(), cw.cols()), (3, 2));
+    assert_eq!(cw.get_flatten_grid(), vec![4, 1, 5, 2, 6, 3]);
+    assert_eq!(cw.get_cell_size(), (2., 1.));
+
+    let ccw = g.rotated90_ccw();
+    assert_eq!((ccw.rows(), ccw.cols()), (3, 2));
+    assert_eq!(ccw.get_flatten_grid(), vec![3, 6, 2, 5, 1, 4]);
+
+    let half = g.rotated180();
+    assert_eq!((half.rows(), half.cols()), (2, 3));
+    assert_eq!(half.get_flatten_grid(), vec![6, 5, 4, 3, 2, 1]);
+    assert_eq!(half.get_cell_size(), (1., 2.));
+
+    assert_eq!(
+        g.rotated90_cw().rotated90_cw().get_flatten_grid(),
+        g.rotated180().get_flatten_grid()
+    );
+    assert_eq!(
+        g.rotated90_cw().rotated90_ccw().get_flatten_grid(),
+        g.get_flatten_grid()
+    );
+}
+
+#[test]
+fn test_transpose_and_flips() {
+    let g = Grid::new_from_vector((2, 3), (1., 2.), vec![1, 2, 3, 4, 5, 6]);
+
+    let t = g.transposed();
+    assert_eq!(t.get_flatten_grid(), vec![1, 4, 2, 5, 3, 6]);
+    assert_eq!((t.rows(), t.cols()), (3, 2));
+    assert_eq!(t.get((2, 1)), Ok(&6));
+
+    assert_eq!(
+        g.flipped_horizontal().get_flatten_grid(),
+        vec![3, 2, 1, 6, 5, 4]
+    );
+    assert_eq!(
+        g.flipped_vertical().get_flatten_grid(),
+        vec![4, 5, 6, 1, 2, 3]
+    );
+}
+
+#[test]
+fn test_in_place_matches_returning() {
+    let transforms = [
+        Transform::Identity,
+        Transform::Rotate90Cw,
+        Transform::Rotate90Ccw,
+        Transform::Rotate180,
+        Transform::Transpose,
+        Transform::FlipHorizontal,
+        Transform::FlipVertical,
+    ];
+
+    for transform in transforms {
+        let src = Grid::new_from_vector((2, 3), (1., 2.), vec![1, 2, 3, 4, 5, 6]);
+        let mut g = Grid::new_from_vector((2, 3), (1., 2.), vec![1, 2, 3, 4, 5, 6]);
+        g.transform(transform);
+        let expected = src.transformed(transform);
+        assert_eq!(
+            (g.rows(), g.cols(), g.get_flatten_grid()),
+            (
+                expected.rows(),
+                expected.cols(),
+                expected.get_flatten_grid()
+            ),
+            "{:?}",
+            transform
+        );
+        assert_eq!(g.get_cell_size(), expected.get_cell_size());
+
+        // The positions map to the same values
+        for pos in src.positions() {
+            let dst = transform.apply(pos, (src.rows(), src.cols()));
+            assert_eq!(g[dst], src[pos]);
+        }
+    }
+
+    let mut g = Grid::new_from_vector((2, 3), (1., 2.), vec![1, 2, 3, 4, 5, 6]);
+    g.rotate90_cw();
+    g.rotate90_ccw();
+    g.rotate180();
+    g.rotate180();
+    g.transpose();
+    g.transpose();
+    g.flip_horizontal();
+    g.flip_vertical();
+    assert_eq!((g.rows(), g.cols()), (2, 3));
+    assert_eq!(g.get_flatten_grid(), vec![6, 5, 4, 3, 2, 1]);
+}
+
+#[test]
+fn test_transform_without_clone() {
+    #[derive(Debug, PartialEq)]
+    struct Piece(u8);
+
+    let mut n = 0;
+    let mut g = Grid::new_with((2, 3), (1., 1.), || {
+        n += 1;
+        Piece(n - 1)
+    });
+    g.rotate90_ccw();
+    assert_eq!(g.get((0, 0)), Ok(&Piece(3)));
+    assert_eq!(g.get((2, 1)), Ok(&Piece(4)));
+}
@@ -0,0 +1,568 @@
+use std::{fmt, iter, slice, vec};
+
+use crate::{Grid, GridErr, GridRead, GridWrite, Pos};
+
+/// Represents a window over the cells of a grid, borrowed without copying them
+///
+/// The positions are local to the window, (0, 0) is the top left cell of the view,
+/// and the view is bounded even when the topology of the grid wraps
+///
+/// The view keeps the slice of the grid from its first cell and the distance between
+/// the rows, so creating it and the views inside of it doesn't allocate, only the views
+/// inside of the halves of `GridViewMut::split_at_col` keep a slice for each row
+///
+/// ```.rust
+/// use das_grid::GridRead;
+///
+/// let grid = das_grid::Grid::new_from_vector((3, 3), (1., 1.), (1..=9).collect());
+/// let view = grid.view((1, 1), 2, 2).unwrap();
+/// assert_eq!(view.get((0, 0)), Ok(&5));
+/// assert_eq!(view.iter().map(|(_, v)| *v).collect::<Vec<_>>(), vec![5, 6, 8, 9]);
+/// assert!(view.get((2, 0)).is_err());
+/// ```
+#[derive(Clone)]
+pub struct GridView<'a, T> {
+    cells: Cells<'a, T>,
+    rows: i32,
+    cols: i32,
+}
+
+/// Represents a mutable window over the cells of a grid, borrowed without copying them
+///
+/// Like `GridView` the positions are local to the window, and the view can be split
+/// on disjoint halves with `split_at_row` and `split_at_col`
+///
+/// ```.rust
+/// let mut grid = das_grid::Grid::new((4, 4), (1., 1.), 0);
+/// let mut view = grid.view_mut((2, 2), 2, 2).unwrap();
+/// view.set((1, 1), 7).unwrap();
+/// assert_eq!(grid.get((3, 3)), Ok(&7));
+/// ```
+pub struct GridViewMut<'a, T> {
+    cells: CellsMut<'a, T>,
+    rows: i32,
+    cols: i32,
+}
+
+/// Iterator over the local positions and mutable values of a `GridViewMut`, row by row
+pub struct ViewIterMut<'b, T> {
+    rows: RowsMut<'b, T>,
+    cols: usize,
+    row: i32,
+    current: iter::Enumerate<slice::IterMut<'b, T>>,
+}
+
+// The cells of a view
+//
+// Cells::Strided is the slice of the grid from the first cell of the view,
+// each row starts `stride` cells after the previous one
+//
+// Cells::Rows are the rows of a view split by cols, the halves interleave inside of
+// the rows of the grid so each row is kept on its own slice
+#[derive(Clone)]
+enum Cells<'a, T> {
+    Strided { cells: &'a [T], stride: usize },
+    Rows(Vec<&'a [T]>),
+}
+
+// The cells of a mutable view, like `Cells`
+enum CellsMut<'a, T> {
+    Strided { cells: &'a mut [T], stride: usize },
+    Rows(Vec<&'a mut [T]>),
+}
+
+// The rows walked by `ViewIterMut`
+enum RowsMut<'b, T> {
+    Strided(iter::Take<slice::ChunksMut<'b, T>>),
+    Rows(vec::IntoIter<&'b mut [T]>),
+}
+
+impl<'a, T> GridView<'a, T> {
+    /// Gets the value of the local position (row, col)
+    ///
+    /// If the position is out of the view it return an error of GridErr::OutOfGrid
+    pub fn get(&self, pos: impl Into<Pos>) -> Result<&'a T, GridErr> {
+        let pos = self.wrap(pos)?;
+        Ok(&self.row(pos.row)[pos.col as usize])
+    }
+
+    /// Creates a view inside of this view, the position is local to this view
+    ///
+    /// If the rows or cols aren't greater than 0 it returns the error GridErr::InvalidSize
+    ///
+    /// If the window doesn't fit inside of this view it return an error of GridErr::OutOfGrid
+    ///
+    /// ```.rust
+    /// let grid = das_grid::Grid::new_from_vector((4, 4), (1., 1.), (0..16).collect());
+    /// let outer = grid.view((1, 1), 3, 3).unwrap();
+    /// let inner = outer.view((1, 1), 2, 2).unwrap();
+    /// assert_eq!(inner.get((0, 0)), Ok(&10));
+    /// ```
+    pub fn view(
+        &self,
+        src: impl Into<Pos>,
+        rows: i32,
+        cols: i32,
+    ) -> Result<GridView<'a, T>, GridErr> {
+        let (src, end) = window(self, src.into(), rows, cols)?;
+        let cells = match &self.cells {
+            Cells::Strided { cells, stride } => Cells::Strided {
+                cells: &cells[offset(src, *stride)..],
+                stride: *stride,
+            },
+            Cells::Rows(rows) => Cells::Rows(
+                rows[src.row as usize..end.row as usize]
+                    .iter()
+                    .map(|row| &row[src.col as usize..end.col as usize])
+                    .collect(),
+            ),
+        };
+        Ok(GridView { cells, rows, cols })
+    }
+
+    // The cells of the local row
+    fn row(&self, row: i32) -> &'a [T] {
+        match &self.cells {
+            Cells::Strided { cells, stride } => {
+                &cells[row as usize * stride..][..self.cols as usize]
+            }
+            Cells::Rows(rows) => rows[row as usize],
+        }
+    }
+}
+
+impl<'a, T> GridViewMut<'a, T> {
+    /// Gets the value of the local position (row, col)
+    ///
+    /// If the position is out of the view it return an error of GridErr::OutOfGrid
+    pub fn get(&self, pos: impl Into<Pos>) -> Result<&T, GridErr> {
+        let pos = self.wrap(pos)?;
+        Ok(&self.row(pos.row)[pos.col as usize])
+    }
+
+    /// Gets the value of the local position (row, col) as mutable
+    ///
+    /// If the position is out of the view it return an error of GridErr::OutOfGrid
+    pub fn get_mut(&mut self, pos: impl Into<Pos>) -> Result<&mut T, GridErr> {
+        let pos = self.wrap(pos)?;
+        Ok(&mut self.row_mut(pos.row)[pos.col as usize])
+    }
+
+    /// Sets a given value to the local position (row, col)
+    ///
+    /// If the position is out of the view it return an error of GridErr::OutOfGrid
+    pub fn set(&mut self, pos: impl Into<Pos>, value: T) -> Result<(), GridErr> {
+        *self.get_mut(pos)? = value;
+        Ok(())
+    }
+
+    /// Returns the local positions and mutable values of every cell, row by row
+    pub fn iter_mut(&mut self) -> ViewIterMut<'_, T> {
+        let rows = match &mut self.cells {
+            CellsMut::Strided { cells, stride } => {
+                RowsMut::Strided(cells.chunks_mut(*stride).take(self.rows as usize))
+            }
+            CellsMut::Rows(rows) => RowsMut::Rows(
+                rows.iter_mut()
+                    .map(|row| &mut **row)
+                    .collect::<Vec<_>>()
+                    .into_iter(),
+            ),
+        };
+        ViewIterMut {
+            rows,
+            cols: self.cols as usize,
+            row: -1,
+            current: [].iter_mut().enumerate(),
+        }
+    }
+
+    /// Creates a read only view inside of this view, the position is local to this view
+    ///
+    /// If the rows or cols aren't greater than 0 it returns the error GridErr::InvalidSize
+    ///
+    /// If the window doesn't fit inside of this view it return an error of GridErr::OutOfGrid
+    pub fn view(
+        &self,
+        src: impl Into<Pos>,
+        rows: i32,
+        cols: i32,
+    ) -> Result<GridView<'_, T>, GridErr> {
+        let (src, end) = window(self, src.into(), rows, cols)?;
+        let cells = match &self.cells {
+            CellsMut::Strided { cells, stride } => Cells::Strided {
+                cells: &cells[offset(src, *stride)..],
+                stride: *stride,
+            },
+            CellsMut::Rows(rows) => Cells::Rows(
+                rows[src.row as usize..end.row as usize]
+                    .iter()
+                    .map(|row| &row[src.col as usize..end.col as usize])
+                    .collect(),
+            ),
+        };
+        Ok(GridView { cells, rows, cols })
+    }
+
+    /// Creates a mutable view inside of this view, the position is local to this view
+    ///
+    /// If the rows or cols aren't greater than 0 it returns the error GridErr::InvalidSize
+    ///
+    /// If the window doesn't fit inside of this view it return an error of GridErr::OutOfGrid
+    pub fn view_mut(
+        &mut self,
+        src: impl Into<Pos>,
+        rows: i32,
+        cols: i32,
+    ) -> Result<GridViewMut<'_, T>, GridErr> {
+        let (src, end) = window(self, src.into(), rows, cols)?;
+        let cells = match &mut self.cells {
+            CellsMut::Strided { cells, stride } => CellsMut::Strided {
+                cells: &mut cells[offset(src, *stride)..],
+                stride: *stride,
+            },
+            CellsMut::Rows(rows) => CellsMut::Rows(
+                rows[src.row as usize..end.row as usize]
+                    .iter_mut()
+                    .map(|row| &mut row[src.col as usize..end.col as usize])
+                    .collect(),
+            ),
+        };
+        Ok(GridViewMut { cells, rows, cols })
+    }
+
+    /// Splits the view in two disjoint views, the rows before the given row and the rest
+    ///
+    /// Both halves can be changed at the same time, like on different threads
+    ///
+    /// If the row is greater than the rows of the view it return an error of GridErr::OutOfGrid,
+    /// the row 0 and the last row + 1 give an empty half
+    ///
+    /// ```.rust
+    /// let mut grid = das_grid::Grid::new((4, 2), (1., 1.), 0);
+    /// let (mut top, mut bottom) = grid.view_mut((0, 0), 4, 2).unwrap().split_at_row(1).unwrap();
+    /// std::thread::scope(|s| {
+    ///     s.spawn(|| top.set((0, 0), 1));
+    ///     s.spawn(|| bottom.set((0, 0), 2));
+    /// });
+    /// assert_eq!(grid.get_col(0).unwrap(), vec![1, 2, 0, 0]);
+    /// ```
+    pub fn split_at_row(
+        self,
+        row: i32,
+    ) -> Result<(GridViewMut<'a, T>, GridViewMut<'a, T>), GridErr> {
+        if row < 0 || row > self.rows {
+            return Err(GridErr::OutOfGrid {
+                pos: Pos::new(row, 0),
+            });
+        }
+
+        let (top, bottom) = match self.cells {
+            CellsMut::Strided { cells, stride } => {
+                // The last row may end before the stride, it is all in the top half
+                let mid = (row as usize * stride).min(cells.len());
+                let (top, bottom) = cells.split_at_mut(mid);
+                (
+                    CellsMut::Strided { cells: top, stride },
+                    CellsMut::Strided {
+                        cells: bottom,
+                        stride,
+                    },
+                )
+            }
+            CellsMut::Rows(mut top) => {
+                let bottom = top.split_off(row as usize);
+                (CellsMut::Rows(top), CellsMut::Rows(bottom))
+            }
+        };
+        Ok((
+            GridViewMut {
+                cells: top,
+                rows: row,
+                cols: self.cols,
+            },
+            GridViewMut {
+                cells: bottom,
+                rows: self.rows - row,
+                cols: self.cols,
+            },
+        ))
+    }
+
+    /// Splits the view in two disjoint views, the cols before the given col and the rest
+    ///
+    /// Both halves can be changed at the same time, like on different threads
+    ///
+    /// The halves interleave inside of the rows of the grid, so unlike the other views
+    /// they keep a vector with a slice for each of their rows
+    ///
+    /// If the col is greater than the cols of the view it return an error of GridErr::OutOfGrid,
+    /// the col 0 and the last col + 1 give an empty half
+    pub fn split_at_col(
+        self,
+        col: i32,
+    ) -> Result<(GridViewMut<'a, T>, GridViewMut<'a, T>), GridErr> {
+        if col < 0 || col > self.cols {
+            return Err(GridErr::OutOfGrid {
+                pos: Pos::new(0, col),
+            });
+        }
+
+        let (cols, at) = (self.cols as usize, col as usize);
+        let (left, right) = match self.cells {
+            CellsMut::Strided { cells, stride } => cells
+                .chunks_mut(stride)
+                .take(self.rows as usize)
+                .map(|row| row[..cols].split_at_mut(at))
+                .unzip(),
+            CellsMut::Rows(rows) => rows.into_iter().map(|row| row.split_at_mut(at)).unzip(),
+        };
+        Ok((
+            GridViewMut {
+                cells: CellsMut::Rows(left),
+                rows: self.rows,
+                cols: col,
+            },
+            GridViewMut {
+                cells: CellsMut::Rows(right),
+                rows: self.rows,
+                cols: self.cols - col,
+            },
+        ))
+    }
+
+    // The cells of the local row
+    fn row(&self, row: i32) -> &[T] {
+        match &self.cells {
+            CellsMut::Strided { cells, stride } => {
+                &cells[row as usize * stride..][..self.cols as usize]
+            }
+            CellsMut::Rows(rows) => rows[row as usize],
+        }
+    }
+
+    // The cells of the local row as mutable
+    fn row_mut(&mut self, row: i32) -> &mut [T] {
+        match &mut self.cells {
+            CellsMut::Strided { cells, stride } => {
+                &mut cells[row as usize * *stride..][..self.cols as usize]
+            }
+            CellsMut::Rows(rows) => rows[row as usize],
+        }
+    }
+}
+
+impl<'b, T> Iterator for ViewIterMut<'b, T> {
+    type Item = (Pos, &'b mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((col, value)) = self.current.next() {
+                return Some((Pos::new(self.row, col as i32), value));
+            }
+
+            let cells = match &mut self.rows {
+                RowsMut::Strided(rows) => &mut rows.next()?[..self.cols],
+                RowsMut::Rows(rows) => rows.next()?,
+            };
+            self.row += 1;
+            self.current = cells.iter_mut().enumerate();
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for GridView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..self.rows).map(|row| self.row(row)))
+            .finish()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for GridViewMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..self.rows).map(|row| self.row(row)))
+            .finish()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a view of the given position and size, borrowing the cells instead of copying them
+    ///
+    /// If the rows or cols aren't greater than 0 it returns the error GridErr::InvalidSize
+    ///
+    /// If the window doesn't fit inside of the grid it return an error of GridErr::OutOfGrid,
+    /// unlike `get_subgrid` the view never bleeds out of the grid
+    ///
+    /// ```.rust
+    /// let grid = das_grid::Grid::new_from_vector((3, 3), (1., 1.), (1..=9).collect());
+    /// let view = grid.view((0, 1), 3, 2).unwrap();
+    /// assert_eq!(view.get((2, 1)), Ok(&9));
+    /// assert!(grid.view((2, 2), 2, 2).is_err());
+    /// ```
+    pub fn view(
+        &self,
+        src: impl Into<Pos>,
+        rows: i32,
+        cols: i32,
+    ) -> Result<GridView<'_, T>, GridErr> {
+        let (src, _) = window(self, src.into(), rows, cols)?;
+        let stride = self.cols as usize;
+        Ok(GridView {
+            cells: Cells::Strided {
+                cells: &self.cells[offset(src, stride)..],
+                stride,
+            },
+            rows,
+            cols,
+        })
+    }
+
+    /// Creates a mutable view of the given position and size, the changes on the view
+    /// are made straight on the cells of the grid
+    ///
+    /// If the rows or cols aren't greater than 0 it returns the error GridErr::InvalidSize
+    ///
+    /// If the window doesn't fit inside of the grid it return an error of GridErr::OutOfGrid
+    ///
+    /// ```.rust
+    /// use das_grid::{Connectivity, GridWrite};
+    ///
+    /// let mut grid = das_grid::Grid::new((3, 3), (1., 1.), 0);
+    /// // The fill stops on the edges of the view
+    /// let mut room = grid.view_mut((0, 0), 2, 2).unwrap();
+    /// room.flood_fill((0, 0), 1, |v| *v == 0, Connectivity::Four).unwrap();
+    /// assert_eq!(grid.get_flatten_grid(), vec![1, 1, 0, 1, 1, 0, 0, 0, 0]);
+    /// ```
+    pub fn view_mut(
+        &mut self,
+        src: impl Into<Pos>,
+        rows: i32,
+        cols: i32,
+    ) -> Result<GridViewMut<'_, T>, GridErr> {
+        let (src, _) = window(self, src.into(), rows, cols)?;
+        let stride = self.cols as usize;
+        Ok(GridViewMut {
+            cells: CellsMut::Strided {
+                cells: &mut self.cells[offset(src, stride)..],
+                stride,
+            },
+            rows,
+            cols,
+        })
+    }
+
+    /// Splits the grid in two disjoint mutable views, the rows before the given row and the rest
+    ///
+    /// If the row is greater than the rows of the grid it return an error of GridErr::OutOfGrid
+    ///
+    /// ```.rust
+    /// let mut grid = das_grid::Grid::new((2, 2), (1., 1.), 0);
+    /// let (mut top, mut bottom) = grid.split_at_row(1).unwrap();
+    /// top.set((0, 1), 1).unwrap();
+    /// bottom.set((0, 1), 2).unwrap();
+    /// assert_eq!(grid.get_flatten_grid(), vec![0, 1, 0, 2]);
+    /// ```
+    pub fn split_at_row(
+        &mut self,
+        row: i32,
+    ) -> Result<(GridViewMut<'_, T>, GridViewMut<'_, T>), GridErr> {
+        let (rows, cols) = (self.rows, self.cols);
+        self.view_mut((0, 0), rows, cols)?.split_at_row(row)
+    }
+
+    /// Splits the grid in two disjoint mutable views, the cols before the given col and the rest
+    ///
+    /// If the col is greater than the cols of the grid it return an error of GridErr::OutOfGrid
+    ///
+    /// ```.rust
+    /// let mut grid = das_grid::Grid::new((2, 3), (1., 1.), 0);
+    /// let (mut left, mut right) = grid.split_at_col(1).unwrap();
+    /// left.set((1, 0), 1).unwrap();
+    /// right.set((1, 0), 2).unwrap();
+    /// assert_eq!(grid.get_flatten_grid(), vec![0, 0, 0, 1, 2, 0]);
+    /// ```
+    pub fn split_at_col(
+        &mut self,
+        col: i32,
+    ) -> Result<(GridViewMut<'_, T>, GridViewMut<'_, T>), GridErr> {
+        let (rows, cols) = (self.rows, self.cols);
+        self.view_mut((0, 0), rows, cols)?.split_at_col(col)
+    }
+}
+
+impl<T> GridRead for GridView<'_, T> {
+    type Item = T;
+
+    fn dimensions(&self) -> (i32, i32) {
+        (self.rows, self.cols)
+    }
+
+    fn get(&self, pos: impl Into<Pos>) -> Result<&T, GridErr> {
+        GridView::get(self, pos)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        (0..self.rows).flat_map(move |row| row_iter(row, self.row(row)))
+    }
+}
+
+impl<T> GridRead for GridViewMut<'_, T> {
+    type Item = T;
+
+    fn dimensions(&self) -> (i32, i32) {
+        (self.rows, self.cols)
+    }
+
+    fn get(&self, pos: impl Into<Pos>) -> Result<&T, GridErr> {
+        GridViewMut::get(self, pos)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        (0..self.rows).flat_map(move |row| row_iter(row, self.row(row)))
+    }
+}
+
+impl<T> GridWrite for GridViewMut<'_, T> {
+    fn get_mut(&mut self, pos: impl Into<Pos>) -> Result<&mut T, GridErr> {
+        GridViewMut::get_mut(self, pos)
+    }
+}
+
+// Checks the size of the window and that it fits inside of the grid,
+// returns the first position and the position after the last one
+fn window<G>(grid: &G, src: Pos, rows: i32, cols: i32) -> Result<(Pos, Pos), GridErr>
+where
+    G: GridRead + ?Sized,
+{
+    if rows <= 0 || cols <= 0 {
+        return Err(GridErr::InvalidSize { rows, cols });
+    }
+
+    let (parent_rows, parent_cols) = grid.dimensions();
+    let last = Pos::new(
+        src.row.saturating_add(rows - 1),
+        src.col.saturating_add(cols - 1),
+    );
+    for pos in [src, last] {
+        if pos.row < 0 || pos.row >= parent_rows || pos.col < 0 || pos.col >= parent_cols {
+            return Err(GridErr::OutOfGrid { pos });
+        }
+    }
+
+    Ok((src, Pos::new(last.row + 1, last.col + 1)))
+}
+
+// The index of the local position on the strided cells
+fn offset(pos: Pos, stride: usize) -> usize {
+    pos.row as usize * stride + pos.col as usize
+}
+
+// The local positions and values of a row of a view
+fn row_iter<T>(row: i32, cells: &[T]) -> impl Iterator<Item = (Pos, &T)> {
+    cells
+        .iter()
+        .enumerate()
+        .map(move |(col, value)| (Pos::new(row, col as i32), value))
+}
@@ -0,0 +1,216 @@
+#![allow(warnings, unused)]
+#[macro_use]
+use pretty_assertions::{assert_eq, assert_ne};
+
+use crate::{Connectivity, Grid, GridErr, GridRead, GridWrite, Pos, Topology};
+
+#[test]
+fn test_view_local_coordinates() {
+    let g = Grid::new_from_vector((4, 5), (1., 1.), (0..20).collect());
+    let v = g.view((1, 2), 2, 3).unwrap();
+    assert_eq!(v.dimensions(), (2, 3));
+    assert_eq!(v.get((0, 0)), Ok(&7));
+    assert_eq!(v.get((1, 2)), Ok(&14));
+    assert_eq!(
+        v.get((1, 3)),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(1, 3)
+        })
+    );
+    assert_eq!(
+        v.get((-1, 0)),
+        Err(GridErr::OutOfGrid {
+            pos: Pos::new(-1, 0)
+        })
+    );
+    assert!(v.in_bounds((1, 1)));
+    assert!(!v.in_bounds((2, 1)));
+
+    let cells = v.iter().map(|(p, v)| (p, *v)).collect::<Vec<_>>();
+    assert_eq!(cells.len(), 6);
+    assert_eq!(cells[0], (Pos::new(0, 0), 7));
+    assert_eq!(cells[5], (Pos::new(1, 2), 14));
+}
+
+#[test]
+fn test_view_bounds() {
+    let mut g = Grid::new_from_vector((3, 3), (1., 1.), (0..9).collect());
+    assert_eq!(
+        g.view((0, 0), 0, 2).err(),
+        Some(GridErr::InvalidSize { rows: 0, cols: 2 })
+    );
+    assert_eq!(
+        g.view((2, 2), 2, 1).err(),
+        Some(GridErr::OutOfGrid {
+            pos: Pos::new(3, 2)
+        })
+    );
+    assert_eq!(
+        g.view((-1, 0), 1, 1).err(),
+        Some(GridErr::OutOfGrid {
+            pos: Pos::new(-1, 0)
+        })
+    );
+    assert!(g.view((0, 0), 3, 3).is_ok());
+
+    // The view is bounded even on a torus
+    g.set_topology(Topology::Torus);
+    assert!(g.view((2, 2), 2, 2).is_err());
+    let v = g.view((0, 0), 3, 3).unwrap();
+    assert_eq!(v.neighbors8((0, 0)).count(), 3);
+}
+
+#[test]
+fn test_view_nested() {
+    let g = Grid::new_from_vector((5, 5), (1., 1.), (0..25).collect());
+    let outer = g.view((1, 1), 4, 4).unwrap();
+    let inner = outer.view((2, 1), 2, 3).unwrap();
+    assert_eq!(inner.get((0, 0)), Ok(&17));
+    assert_eq!(inner.get((1, 2)), Ok(&24));
+    assert!(outer.view((2, 2), 3, 1).is_err());
+
+    // The nested view outlives the outer view
+    let inner = {
+        let outer = g.view((0, 0), 2, 2).unwrap();
+        outer.view((1, 1), 1, 1).unwrap()
+    };
+    assert_eq!(inner.get((0, 0)), Ok(&6));
+}
+
+#[test]
+fn test_view_mut() {
+    let mut g = Grid::new((4, 4), (1., 1.), 0);
+    {
+        let mut v = g.view_mut((1, 1), 3, 3).unwrap();
+        for (pos, value) in v.iter_mut() {
+            *value = pos.row * 10 + pos.col;
+        }
+
+        let mut inner = v.view_mut((1, 1), 2, 2).unwrap();
+        *inner.get_mut((1, 1)).unwrap() = 99;
+        assert_eq!(
+            inner.set((2, 0), 1),
+            Err(GridErr::OutOfGrid {
+                pos: Pos::new(2, 0)
+            })
+        );
+
+        assert_eq!(v.view((2, 0), 1, 3).unwrap().get((0, 2)), Ok(&99));
+    }
+    assert_eq!(
+        g.get_flatten_grid(),
+        vec![0, 0, 0, 0, 0, 0, 1, 2, 0, 10, 11, 12, 0, 20, 21, 99]
+    );
+}
+
+#[test]
+fn test_view_mut_algorithms() {
+    let mut g = Grid::new_from_vector((3, 4), (1., 1.), "....#..#....".chars().collect());
+    let mut v = g.view_mut((0, 1), 3, 3).unwrap();
+    let path = v
+        .bfs_path((0, 0), (2, 2), Connectivity::Four, |c| *c == '.')
+        .unwrap();
+    assert_eq!(path.cost, 4);
+
+    assert_eq!(
+        v.flood_fill((0, 0), '~', |c| *c == '.', Connectivity::Four),
+        Ok(8)
+    );
+    assert_eq!(g.get_row(0).unwrap(), vec!['.', '~', '~', '~']);
+    assert_eq!(g.get_row(1).unwrap(), vec!['#', '~', '~', '#']);
+}
+
+#[test]
+fn test_split_at_row() {
+    let mut g = Grid::new((3, 2), (1., 1.), 0);
+    let (mut top, mut bottom) = g.split_at_row(2).unwrap();
+    assert_eq!(top.dimensions(), (2, 2));
+    assert_eq!(bottom.dimensions(), (1, 2));
+    top.iter_mut().for_each(|(_, v)| *v = 1);
+    bottom.set((0, 1), 2).unwrap();
+    assert!(bottom.set((1, 0), 2).is_err());
+    assert_eq!(g.get_flatten_grid(), vec![1, 1, 1, 1, 0, 2]);
+
+    let (top, bottom) = g.split_at_row(0).unwrap();
+    assert_eq!(top.dimensions(), (0, 2));
+    assert_eq!(top.iter().count(), 0);
+    assert_eq!(bottom.dimensions(), (3, 2));
+    assert_eq!(
+        g.split_at_row(4).err(),
+        Some(GridErr::OutOfGrid {
+            pos: Pos::new(4, 0)
+        })
+    );
+}
+
+#[test]
+fn test_split_at_col() {
+    let mut g = Grid::new_from_vector((2, 4), (1., 1.), (0..8).collect());
+    let (mut left, right) = g.split_at_col(1).unwrap();
+    assert_eq!(left.dimensions(), (2, 1));
+    assert_eq!(right.dimensions(), (2, 3));
+    assert_eq!(right.get((1, 0)), Ok(&5));
+
+    // The halves can be split again, each in its own thread
+    let (mut top_right, mut bottom_right) = right.split_at_row(1).unwrap();
+    std::thread::scope(|s| {
+        s.spawn(|| left.iter_mut().for_each(|(_, v)| *v = -1));
+        s.spawn(|| top_right.iter_mut().for_each(|(_, v)| *v *= 10));
+        s.spawn(|| bottom_right.set((0, 2), 0));
+    });
+    assert_eq!(g.get_flatten_grid(), vec![-1, 10, 20, 30, -1, 5, 6, 0]);
+
+    assert_eq!(
+        g.split_at_col(-1).err(),
+        Some(GridErr::OutOfGrid {
+            pos: Pos::new(0, -1)
+        })
+    );
+}
+
+#[test]
+fn test_split_nested_views() {
+    let mut g = Grid::new_from_vector((3, 4), (1., 1.), (0..12).collect());
+    let (left, right) = g.view_mut((0, 0), 3, 4).unwrap().split_at_col(2).unwrap();
+    let (mut top_left, bottom_left) = left.split_at_row(1).unwrap();
+    let (mut middle, mut last) = right.split_at_col(1).unwrap();
+    assert_eq!(format!("{:?}", bottom_left), "[[4, 5], [8, 9]]");
+
+    top_left.set((0, 1), -1).unwrap();
+    middle.iter_mut().for_each(|(pos, v)| *v = 100 + pos.row);
+    assert_eq!(last.dimensions(), (3, 1));
+    *last.get_mut((2, 0)).unwrap() = 0;
+    assert_eq!(bottom_left.view((1, 1), 1, 1).unwrap().get((0, 0)), Ok(&9));
+    assert_eq!(
+        g.get_flatten_grid(),
+        vec![0, -1, 100, 3, 4, 5, 101, 7, 8, 9, 102, 0]
+    );
+
+    // The copies of a view read the same cells
+    let v = g.view((1, 1), 2, 2).unwrap();
+    let copy = v.clone();
+    assert_eq!(
+        copy.iter().map(|(_, v)| *v).collect::<Vec<_>>(),
+        vec![5, 101, 9, 102]
+    );
+}
+
+#[test]
+fn test_split_inner_view_at_row() {
+    let mut g = Grid::new_from_vector((4, 4), (1., 1.), (0..16).collect());
+    let view = g.view_mut((1, 1), 3, 2).unwrap();
+    let (top, mut bottom) = view.split_at_row(2).unwrap();
+    assert_eq!(format!("{:?}", top), "[[5, 6], [9, 10]]");
+    assert_eq!(format!("{:?}", bottom), "[[13, 14]]");
+
+    // The last row of the view ends before the end of the grid
+    let (all, rest) = bottom
+        .view_mut((0, 0), 1, 2)
+        .unwrap()
+        .split_at_row(1)
+        .unwrap();
+    assert_eq!(all.dimensions(), (1, 2));
+    assert_eq!(rest.iter().count(), 0);
+    bottom.iter_mut().for_each(|(pos, v)| *v = -pos.col);
+    assert_eq!(g.get_row(3).unwrap(), vec![12, 0, -1, 15]);
+}